@@ -1,170 +1,439 @@
-extern crate bindgen;
-
 use std::env;
+use std::fs::File;
+use std::io::{BufReader, Read};
 use std::path::{Path, PathBuf};
 
-fn pre_compiled_lib_exists() -> bool {
-    let embree_loc = PathBuf::from("./deps/embree3");
-    embree_loc.exists()
+use sha2::{Digest, Sha256};
+
+/// Default location of the prebuilt embree archive, relative to the
+/// crate root
+const PREBUILT_ARCHIVE: &str = "deps/embree-3.13.2.x86_64.linux.tar.gz";
+
+/// Hex encoded SHA-256 of the embree 3.13.2 linux release archive
+/// expected at [`PREBUILT_ARCHIVE`], [`None`] while no digest of the
+/// release is pinned, `EMBREE_RUST_PREBUILT_SHA256` must be set then
+const PREBUILT_ARCHIVE_SHA256: Option<&str> = None;
+
+/// Whether the archive at [`PREBUILT_ARCHIVE`] is known to be built
+/// with `EMBREE_RAY_MASK`, which the ray masks of the crate need. The
+/// option is off by default in embree 3, so embree is compiled from
+/// source unless the archive is confirmed to have it.
+const PREBUILT_ARCHIVE_RAY_MASK: bool = false;
+
+/// The `backface-culling` feature compiles embree with
+/// `EMBREE_BACKFACE_CULLING`
+fn backface_culling_enabled() -> bool {
+    env::var("CARGO_FEATURE_BACKFACE_CULLING").is_ok()
 }
 
-/// [`source_dir`] is embree source code path
-///
-/// [`build_dir`] is path to which embree is compiled, generally
-/// `source_dir/build`
-///
-/// [`to_dir`] is the path to which embree is installed
-fn compile_embree(source_dir: impl AsRef<Path>, build_dir: impl AsRef<Path>) {
-    std::process::Command::new("cmake")
-        .current_dir(&build_dir)
-        .arg("CMAKE_BUILD_TYPE=Release")
-        .arg("-DEMBREE_ISPC_SUPPORT=false")
-        .arg("-DEMBREE_TUTORIALS=false")
-        .arg("-DEMBREE_STATIC_LIB=true")
-        .arg(source_dir.as_ref())
-        .output()
-        .expect("cmake may not be available on system");
-    // TODO: user customizable number of processes, embree is
-    // expensive to compile, completely utilizes the CPU, RAM and SWAP
-    // thus bringing the system to complete halt (at least on a XPS 15
-    // 9570 with i7-8750H and 16GB RAM)
-    std::process::Command::new("make")
-        .current_dir(&build_dir)
-        .arg("-j")
-        .arg("6")
-        .output()
-        .expect("make may not be available on system");
-}
-
-fn install_embree(build_dir: impl AsRef<Path>, to_dir: impl AsRef<Path>) {
-    std::process::Command::new("cmake")
-        .current_dir(&build_dir)
-        .arg("--install")
-        .arg(".")
-        .arg("--prefix")
-        .arg(to_dir.as_ref())
-        .output()
-        .expect("failed to install the library");
-}
-
-fn compile_and_generate_embree_lib() {
-    let root_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
-        .canonicalize()
-        .unwrap();
-
-    let embree_source_dir = {
-        let mut embree_source_dir = root_dir.clone();
-        embree_source_dir.push("extern/embree");
-        embree_source_dir.canonicalize().unwrap()
-    };
+/// The `internal-tasking` feature compiles embree with its internal
+/// tasking system instead of TBB
+fn internal_tasking_enabled() -> bool {
+    env::var("CARGO_FEATURE_INTERNAL_TASKING").is_ok()
+}
 
-    let embree_build_dir = {
-        let mut embree_build_dir = embree_source_dir.clone();
-        embree_build_dir.push("build");
+/// Maximum instance nesting level, the largest of the enabled
+/// `max-instance-level-*` features
+fn max_instance_level() -> u32 {
+    [2, 4, 8]
+        .into_iter()
+        .filter(|level| env::var(format!("CARGO_FEATURE_MAX_INSTANCE_LEVEL_{}", level)).is_ok())
+        .max()
+        .unwrap_or(1)
+}
 
-        if !embree_build_dir.exists() {
-            std::fs::create_dir_all(&embree_build_dir)
-                .expect("could not create build dir for Embree");
-        }
+/// The `embree4` feature builds against the embree 4 API instead of
+/// embree 3
+fn embree4_enabled() -> bool {
+    env::var("CARGO_FEATURE_EMBREE4").is_ok()
+}
 
-        embree_build_dir.canonicalize().unwrap()
-    };
+/// Name of the embree library, which includes the major version
+fn embree_lib() -> &'static str {
+    if embree4_enabled() {
+        "embree4"
+    } else {
+        "embree3"
+    }
+}
 
-    compile_embree(&embree_source_dir, &embree_build_dir);
+/// ISAs that embree kernels can be compiled for besides SSE2, the
+/// name of the CMake option and the name of the library of the
+/// kernels. Each is selected by the `isa-*` feature of the same name.
+const ISAS: [(&str, &str); 4] = [
+    ("SSE42", "embree_sse42"),
+    ("AVX", "embree_avx"),
+    ("AVX2", "embree_avx2"),
+    ("AVX512", "embree_avx512"),
+];
 
-    let embree_deps_dir = {
-        let mut embree_deps_dir = root_dir;
-        embree_deps_dir.push("deps/embree3");
+/// Check if the `isa-*` feature of the ISA is enabled, all of them
+/// are by default
+fn isa_enabled(isa: &str) -> bool {
+    env::var(format!("CARGO_FEATURE_ISA_{}", isa)).is_ok()
+}
 
-        if !embree_deps_dir.exists() {
-            std::fs::create_dir_all(&embree_deps_dir)
-                .expect("could not create deps dir for Embree");
-        }
+/// The precompiled library is embree 3 built with the default
+/// options and `EMBREE_RAY_MASK`, so embree must be compiled from
+/// source when any of the options differ
+fn compile_required() -> bool {
+    // a prebuilt archive given by the user may target any platform
+    let prebuilt_target_supported = env::var("EMBREE_RUST_PREBUILT_ARCHIVE").is_ok()
+        || (PREBUILT_ARCHIVE_RAY_MASK
+            && env::var("CARGO_CFG_TARGET_OS").unwrap() == "linux"
+            && env::var("CARGO_CFG_TARGET_ARCH").unwrap() == "x86_64");
 
-        embree_deps_dir.canonicalize().unwrap()
+    env::var("EMBREE_RUST_FORCE_COMPILE").is_ok()
+        || !prebuilt_target_supported
+        || embree4_enabled()
+        || backface_culling_enabled()
+        || internal_tasking_enabled()
+        || max_instance_level() != 1
+        || !ISAS.iter().all(|(isa, _)| isa_enabled(isa))
+}
+
+/// Compile the embree submodule (`extern/embree4` with the `embree4`
+/// feature) and install it into `OUT_DIR`, returns the install dir.
+///
+/// The number of parallel jobs is taken from `EMBREE_RUST_JOBS` if
+/// set, otherwise from cargo (`NUM_JOBS`). Embree is expensive to
+/// compile, lower the number of jobs if the system runs out of
+/// memory.
+fn compile_embree() -> PathBuf {
+    let submodule = if embree4_enabled() {
+        "extern/embree4"
+    } else {
+        "extern/embree"
     };
+    let source_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join(submodule);
+    if !source_dir.join("CMakeLists.txt").exists() {
+        panic!(
+            "embree source is not available at {}, run `git submodule update --init`",
+            source_dir.display()
+        );
+    }
+
+    let on_off = |enabled: bool| if enabled { "ON" } else { "OFF" };
 
-    install_embree(&embree_build_dir, &embree_deps_dir);
+    let mut config = cmake::Config::new(&source_dir);
+    config
+        .profile("Release")
+        .define("CMAKE_INSTALL_LIBDIR", "lib")
+        .define("EMBREE_ISPC_SUPPORT", "OFF")
+        .define("EMBREE_TUTORIALS", "OFF")
+        .define("EMBREE_STATIC_LIB", "ON")
+        .define("EMBREE_RAY_MASK", "ON")
+        .define(
+            "EMBREE_BACKFACE_CULLING",
+            on_off(backface_culling_enabled()),
+        )
+        .define(
+            "EMBREE_TASKING_SYSTEM",
+            if internal_tasking_enabled() {
+                "INTERNAL"
+            } else {
+                "TBB"
+            },
+        )
+        .define(
+            "EMBREE_MAX_INSTANCE_LEVEL_COUNT",
+            max_instance_level().to_string(),
+        )
+        .define("EMBREE_ISA_SSE2", "ON");
+    ISAS.iter().for_each(|(isa, _)| {
+        config.define(format!("EMBREE_ISA_{}", isa), on_off(isa_enabled(isa)));
+    });
+
+    if let Ok(jobs) = env::var("EMBREE_RUST_JOBS") {
+        let jobs: usize = jobs
+            .parse()
+            .unwrap_or_else(|_| panic!("EMBREE_RUST_JOBS must be a number, got {}", jobs));
+        // understood by every generator, unlike `-j` which MSBuild
+        // rejects
+        config.env("CMAKE_BUILD_PARALLEL_LEVEL", jobs.to_string());
+        // the cmake crate passes `--parallel $NUM_JOBS` and the
+        // jobserver of cargo to the build tool, both take precedence
+        // over the environment
+        env::set_var("NUM_JOBS", jobs.to_string());
+        env::remove_var("CARGO_MAKEFLAGS");
+    }
 
-    std::fs::remove_dir_all(embree_build_dir).unwrap();
+    // panics with the output of cmake if any step fails
+    config.build()
 }
 
-fn use_precompiled_lib() {
-    let root_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
-        .canonicalize()
-        .unwrap();
+/// Verify and extract the prebuilt embree archive into `OUT_DIR`,
+/// returns the dir containing `lib` and `include`.
+///
+/// The archive is taken from `EMBREE_RUST_PREBUILT_ARCHIVE` if set,
+/// its SHA-256 must then be given by `EMBREE_RUST_PREBUILT_SHA256`.
+/// Otherwise the archive at [`PREBUILT_ARCHIVE`] is checked against
+/// [`PREBUILT_ARCHIVE_SHA256`], or against
+/// `EMBREE_RUST_PREBUILT_SHA256` if no digest is pinned.
+fn extract_prebuilt_embree() -> PathBuf {
+    println!("cargo:rerun-if-env-changed=EMBREE_RUST_PREBUILT_ARCHIVE");
+    println!("cargo:rerun-if-env-changed=EMBREE_RUST_PREBUILT_SHA256");
+
+    let archive_override = env::var("EMBREE_RUST_PREBUILT_ARCHIVE").ok();
+    let archive = archive_override
+        .as_ref()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join(PREBUILT_ARCHIVE)
+        });
+    println!("cargo:rerun-if-changed={}", archive.display());
+    if !archive.is_file() {
+        panic!(
+            "prebuilt embree archive not found at {}, place it there, point \
+             EMBREE_RUST_PREBUILT_ARCHIVE to it or set EMBREE_RUST_FORCE_COMPILE to compile \
+             embree from source",
+            archive.display()
+        );
+    }
+
+    let expected = match archive_override {
+        Some(_) => env::var("EMBREE_RUST_PREBUILT_SHA256").ok(),
+        None => PREBUILT_ARCHIVE_SHA256
+            .map(str::to_string)
+            .or_else(|| env::var("EMBREE_RUST_PREBUILT_SHA256").ok()),
+    }
+    .unwrap_or_else(|| {
+        panic!(
+            "no pinned digest for the prebuilt embree archive {}, set \
+             EMBREE_RUST_PREBUILT_SHA256 to its SHA-256",
+            archive.display()
+        )
+    });
+
+    let actual = sha256(&archive);
+    if !actual.eq_ignore_ascii_case(expected.trim()) {
+        panic!(
+            "checksum of the prebuilt embree archive {} does not match, expected {} got {}",
+            archive.display(),
+            expected.trim(),
+            actual
+        );
+    }
 
-    let deps_dir = {
-        let mut deps_dir = root_dir;
-        deps_dir.push("deps/");
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap()).join("embree-prebuilt");
+    if out_dir.exists() {
+        std::fs::remove_dir_all(&out_dir).expect("could not remove the previous prebuilt embree");
+    }
+    let file = File::open(&archive).expect("could not open the prebuilt embree archive");
+    tar::Archive::new(flate2::read::GzDecoder::new(BufReader::new(file)))
+        .unpack(&out_dir)
+        .unwrap_or_else(|err| {
+            panic!(
+                "could not extract the prebuilt embree archive {}: {}",
+                archive.display(),
+                err
+            )
+        });
+
+    // the archive either contains `lib` and `include` directly or a
+    // single dir containing them
+    if out_dir.join("include").exists() {
+        return out_dir;
+    }
+    let mut entries: Vec<_> = std::fs::read_dir(&out_dir)
+        .unwrap()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    match (entries.pop(), entries.is_empty()) {
+        (Some(dir), true) if dir.join("include").exists() => dir,
+        _ => panic!(
+            "prebuilt embree archive {} must contain lib and include dirs",
+            archive.display()
+        ),
+    }
+}
 
-        if !deps_dir.exists() {
-            std::fs::create_dir_all(&deps_dir).expect("could not create deps dir for Embree");
+/// Hex encoded SHA-256 of the file.
+fn sha256(path: &Path) -> String {
+    let mut file = BufReader::new(File::open(path).expect("could not open file to checksum"));
+    let mut hasher = Sha256::new();
+    let mut buffer = [0; 64 * 1024];
+    loop {
+        let read = file
+            .read(&mut buffer)
+            .expect("could not read file to checksum");
+        if read == 0 {
+            break;
         }
+        hasher.update(&buffer[..read]);
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// Find the embree installed on the system and link it dynamically,
+/// returns the include dirs of embree.
+///
+/// `EMBREE_DIR` (the install prefix of embree) is tried first, then
+/// pkg-config and then the CMake config files of embree in the usual
+/// prefixes.
+#[cfg(feature = "system")]
+fn link_system_embree() -> Vec<PathBuf> {
+    println!("cargo:rerun-if-env-changed=EMBREE_DIR");
+    println!("cargo:rerun-if-env-changed=CMAKE_PREFIX_PATH");
+
+    if let Ok(embree_dir) = env::var("EMBREE_DIR") {
+        let embree_dir = PathBuf::from(embree_dir);
+        let lib_dir = find_lib_dir(&embree_dir).unwrap_or_else(|| {
+            panic!(
+                "EMBREE_DIR is set to {} but {} library is not found in it",
+                embree_dir.display(),
+                embree_lib()
+            )
+        });
+        println!("cargo:rustc-link-search=native={}", lib_dir.display());
+        println!("cargo:rustc-link-lib=dylib={}", embree_lib());
+        return vec![embree_dir.join("include")];
+    }
 
-        deps_dir.canonicalize().unwrap()
+    if let Ok(library) = pkg_config::Config::new()
+        .atleast_version(if embree4_enabled() { "4.0" } else { "3.0" })
+        .probe(embree_lib())
+    {
+        return library.include_paths;
+    }
+
+    // embree installs `<prefix>/<lib dir>/cmake/embree-<version>/`
+    // with its CMake config
+    let cmake_config_prefix = if embree4_enabled() {
+        "embree-4"
+    } else {
+        "embree-3"
     };
+    let prefixes = env::var("CMAKE_PREFIX_PATH")
+        .map(|paths| env::split_paths(&paths).collect())
+        .unwrap_or_else(|_| Vec::new())
+        .into_iter()
+        .chain([PathBuf::from("/usr/local"), PathBuf::from("/usr")]);
+    for prefix in prefixes {
+        let lib_dir = match find_lib_dir(&prefix) {
+            Some(lib_dir) => lib_dir,
+            None => continue,
+        };
+        let has_cmake_config = std::fs::read_dir(lib_dir.join("cmake"))
+            .map(|entries| {
+                entries.filter_map(Result::ok).any(|entry| {
+                    entry
+                        .file_name()
+                        .to_string_lossy()
+                        .starts_with(cmake_config_prefix)
+                })
+            })
+            .unwrap_or(false);
+        if has_cmake_config {
+            println!("cargo:rustc-link-search=native={}", lib_dir.display());
+            println!("cargo:rustc-link-lib=dylib={}", embree_lib());
+            return vec![prefix.join("include")];
+        }
+    }
 
-    std::process::Command::new("tar")
-        .current_dir(deps_dir)
-        .arg("-xf")
-        .arg("embree-3.13.2.x86_64.linux.tar.gz")
-        .output()
-        .expect("maybe tar is not available to unzip precompiled lib");
+    panic!(
+        "could not find embree installed on the system through pkg-config or CMake, \
+         set EMBREE_DIR to the install prefix of embree"
+    );
 }
 
-fn main() {
-    println!("cargo:rerun-if-changed=wrapper.h");
-    // unlikely but incase
-    println!("cargo:rerun-if-changed=deps/embree3/");
+#[cfg(not(feature = "system"))]
+fn link_system_embree() -> Vec<PathBuf> {
+    unreachable!("system feature is not enabled")
+}
+
+/// Find the dir containing the embree library in the install
+/// prefix.
+#[cfg(feature = "system")]
+fn find_lib_dir(prefix: &std::path::Path) -> Option<PathBuf> {
+    [
+        "lib",
+        "lib64",
+        "lib/x86_64-linux-gnu",
+        "lib/aarch64-linux-gnu",
+    ]
+    .iter()
+    .map(|lib_dir| prefix.join(lib_dir))
+    .find(|lib_dir| {
+        [
+            format!("lib{}.so", embree_lib()),
+            format!("lib{}.dylib", embree_lib()),
+            format!("{}.lib", embree_lib()),
+        ]
+        .iter()
+        .any(|lib| lib_dir.join(lib).exists())
+    })
+}
+
+/// Compile or extract the bundled embree and link it statically,
+/// returns the include dirs of embree.
+fn link_bundled_embree() -> Vec<PathBuf> {
+    println!("cargo:rerun-if-env-changed=EMBREE_RUST_FORCE_COMPILE");
+    println!("cargo:rerun-if-env-changed=EMBREE_RUST_JOBS");
     // rerun if embree verions is updated in the gitmodules
     println!("cargo:rerun-if-changed=extern/embree/");
+    println!("cargo:rerun-if-changed=extern/embree4/");
 
-    if pre_compiled_lib_exists() {
-        println!("pre compiled embree already exists at deps/embree3");
-    } else if env::var("EMBREE_RUST_FORCE_COMPILE").is_ok() {
-        compile_and_generate_embree_lib();
+    let embree_dir = if compile_required() {
+        // lets the tests rely on ray masks
+        println!("cargo:rustc-cfg=embree_ray_mask");
+        compile_embree()
     } else {
-        // use precompiled library if available
-        if cfg!(target_os = "linux") {
-            use_precompiled_lib();
-        } else {
-            compile_and_generate_embree_lib();
-        }
-    }
+        extract_prebuilt_embree()
+    };
 
+    println!(
+        "cargo:rustc-link-search=native={}",
+        embree_dir.join("lib").display()
+    );
     println!("cargo:rustc-link-lib=dylib=stdc++");
-    println!("cargo:rustc-link-lib=static=embree3");
-    println!("cargo:rustc-link-lib=static=embree_sse42");
-    println!("cargo:rustc-link-lib=static=embree_avx");
-    println!("cargo:rustc-link-lib=static=embree_avx2");
-    println!("cargo:rustc-link-lib=static=embree_avx512");
+    println!("cargo:rustc-link-lib=static={}", embree_lib());
+    ISAS.iter()
+        .filter(|(isa, _)| isa_enabled(isa))
+        .for_each(|(_, lib)| {
+            println!("cargo:rustc-link-lib=static={}", lib);
+        });
     println!("cargo:rustc-link-lib=static=lexers");
     println!("cargo:rustc-link-lib=static=math");
     println!("cargo:rustc-link-lib=static=simd");
     println!("cargo:rustc-link-lib=static=sys");
     println!("cargo:rustc-link-lib=static=tasking");
-    println!("cargo:rustc-link-lib=dylib=tbb");
+    if !internal_tasking_enabled() {
+        println!("cargo:rustc-link-lib=dylib=tbb");
+    }
 
-    let current_dir = std::path::PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
-    let embree_lib_path = {
-        let mut embree_lib_path = current_dir;
-        embree_lib_path.push("deps/embree3/lib");
-        embree_lib_path
-    };
-    println!(
-        "cargo:rustc-link-search={}",
-        embree_lib_path.canonicalize().unwrap().to_str().unwrap()
-    );
+    vec![embree_dir.join("include")]
+}
+
+/// Generate the bindings from the embree headers into `OUT_DIR`.
+///
+/// If `EMBREE_RUST_UPDATE_BINDINGS` is set the pregenerated bindings
+/// in `src/bindings` are replaced by the generated ones, they must be
+/// generated without any `max-instance-level-*` feature.
+#[cfg(feature = "bindgen")]
+fn generate_bindings(include_dirs: &[PathBuf]) {
+    println!("cargo:rerun-if-env-changed=EMBREE_RUST_UPDATE_BINDINGS");
 
     let bindings = bindgen::Builder::default()
         .header("wrapper.h")
+        .clang_args(embree4_enabled().then_some("-DEMBREE_RUST_EMBREE4"))
+        .clang_args(
+            include_dirs
+                .iter()
+                .map(|include_dir| format!("-I{}", include_dir.display())),
+        )
         // Tell cargo to invalidate the built crate whenever any of the
         // included header files changed.
         .parse_callbacks(Box::new(bindgen::CargoCallbacks))
         .allowlist_type("RTC.*")
         .allowlist_function("rtc.*")
+        .allowlist_var("RTC_MAX_INSTANCE_LEVEL_COUNT")
         .no_copy("RTC.*")
+        // keeps the bindings portable across targets, `size_t` is not
+        // `c_ulong` everywhere
+        .size_t_is_usize(true)
         .generate()
         .expect("Unable to generate bindings");
 
@@ -173,4 +442,37 @@ fn main() {
     bindings
         .write_to_file(out_path.join("bindings.rs"))
         .expect("Couldn't write bindings!");
+
+    if env::var("EMBREE_RUST_UPDATE_BINDINGS").is_ok() {
+        if max_instance_level() != 1 {
+            panic!("pregenerated bindings must be updated without a max-instance-level-* feature");
+        }
+        let pregenerated = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join(format!("src/bindings/{}.rs", embree_lib()));
+        bindings
+            .write_to_file(pregenerated)
+            .expect("Couldn't update the pregenerated bindings!");
+    }
+}
+
+#[cfg(not(feature = "bindgen"))]
+fn generate_bindings(_include_dirs: &[PathBuf]) {
+    unreachable!("bindgen feature is not enabled")
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=wrapper.h");
+    println!("cargo:rustc-check-cfg=cfg(embree_ray_mask)");
+
+    // the `system` feature links the embree installed on the system
+    // instead of the bundled one
+    let include_dirs = if cfg!(feature = "system") {
+        link_system_embree()
+    } else {
+        link_bundled_embree()
+    };
+
+    if cfg!(feature = "bindgen") {
+        generate_bindings(&include_dirs);
+    }
 }
@@ -1,4 +1,4 @@
-use embree_rust::{Embree, Ray, SceneID, Sphere, Triangle, Vec3, Vert, INVALID_GEOMETRY_ID};
+use embree_rust::{Embree, Ray, SceneID, Sphere, Triangle, Vec3, Vert};
 use image::Pixel;
 
 fn generate_cube() -> (Vec<Vert>, Vec<Triangle>) {
@@ -55,7 +55,7 @@ fn trace_image(
                 camera_focal_length,
             );
 
-            let ray_hit = embree.intersect_scene(
+            let hit_info = embree.closest_hit(
                 scene_id,
                 Ray::new(
                     Vec3::new(camera_origin.0, camera_origin.1, camera_origin.2),
@@ -66,8 +66,8 @@ fn trace_image(
                 ),
             );
 
-            let rgb: [f32; 3] = if ray_hit.hit.geomID != INVALID_GEOMETRY_ID {
-                // [ray_hit.hit.u, ray_hit.hit.v, 0.0]
+            let rgb: [f32; 3] = if let Some(_hit_info) = hit_info {
+                // [hit_info.uv.0, hit_info.uv.1, 0.0]
                 [1.0, 0.0, 0.0]
             } else {
                 [0.0, 0.0, 0.0]
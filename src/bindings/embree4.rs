@@ -0,0 +1,6222 @@
+/* automatically generated by rust-bindgen 0.60.1 */
+
+pub const RTC_MAX_INSTANCE_LEVEL_COUNT: u32 = 1;
+pub const RTCFormat_RTC_FORMAT_UNDEFINED: RTCFormat = 0;
+pub const RTCFormat_RTC_FORMAT_UCHAR: RTCFormat = 4097;
+pub const RTCFormat_RTC_FORMAT_UCHAR2: RTCFormat = 4098;
+pub const RTCFormat_RTC_FORMAT_UCHAR3: RTCFormat = 4099;
+pub const RTCFormat_RTC_FORMAT_UCHAR4: RTCFormat = 4100;
+pub const RTCFormat_RTC_FORMAT_CHAR: RTCFormat = 8193;
+pub const RTCFormat_RTC_FORMAT_CHAR2: RTCFormat = 8194;
+pub const RTCFormat_RTC_FORMAT_CHAR3: RTCFormat = 8195;
+pub const RTCFormat_RTC_FORMAT_CHAR4: RTCFormat = 8196;
+pub const RTCFormat_RTC_FORMAT_USHORT: RTCFormat = 12289;
+pub const RTCFormat_RTC_FORMAT_USHORT2: RTCFormat = 12290;
+pub const RTCFormat_RTC_FORMAT_USHORT3: RTCFormat = 12291;
+pub const RTCFormat_RTC_FORMAT_USHORT4: RTCFormat = 12292;
+pub const RTCFormat_RTC_FORMAT_SHORT: RTCFormat = 16385;
+pub const RTCFormat_RTC_FORMAT_SHORT2: RTCFormat = 16386;
+pub const RTCFormat_RTC_FORMAT_SHORT3: RTCFormat = 16387;
+pub const RTCFormat_RTC_FORMAT_SHORT4: RTCFormat = 16388;
+pub const RTCFormat_RTC_FORMAT_UINT: RTCFormat = 20481;
+pub const RTCFormat_RTC_FORMAT_UINT2: RTCFormat = 20482;
+pub const RTCFormat_RTC_FORMAT_UINT3: RTCFormat = 20483;
+pub const RTCFormat_RTC_FORMAT_UINT4: RTCFormat = 20484;
+pub const RTCFormat_RTC_FORMAT_INT: RTCFormat = 24577;
+pub const RTCFormat_RTC_FORMAT_INT2: RTCFormat = 24578;
+pub const RTCFormat_RTC_FORMAT_INT3: RTCFormat = 24579;
+pub const RTCFormat_RTC_FORMAT_INT4: RTCFormat = 24580;
+pub const RTCFormat_RTC_FORMAT_ULLONG: RTCFormat = 28673;
+pub const RTCFormat_RTC_FORMAT_ULLONG2: RTCFormat = 28674;
+pub const RTCFormat_RTC_FORMAT_ULLONG3: RTCFormat = 28675;
+pub const RTCFormat_RTC_FORMAT_ULLONG4: RTCFormat = 28676;
+pub const RTCFormat_RTC_FORMAT_LLONG: RTCFormat = 32769;
+pub const RTCFormat_RTC_FORMAT_LLONG2: RTCFormat = 32770;
+pub const RTCFormat_RTC_FORMAT_LLONG3: RTCFormat = 32771;
+pub const RTCFormat_RTC_FORMAT_LLONG4: RTCFormat = 32772;
+pub const RTCFormat_RTC_FORMAT_FLOAT: RTCFormat = 36865;
+pub const RTCFormat_RTC_FORMAT_FLOAT2: RTCFormat = 36866;
+pub const RTCFormat_RTC_FORMAT_FLOAT3: RTCFormat = 36867;
+pub const RTCFormat_RTC_FORMAT_FLOAT4: RTCFormat = 36868;
+pub const RTCFormat_RTC_FORMAT_FLOAT5: RTCFormat = 36869;
+pub const RTCFormat_RTC_FORMAT_FLOAT6: RTCFormat = 36870;
+pub const RTCFormat_RTC_FORMAT_FLOAT7: RTCFormat = 36871;
+pub const RTCFormat_RTC_FORMAT_FLOAT8: RTCFormat = 36872;
+pub const RTCFormat_RTC_FORMAT_FLOAT9: RTCFormat = 36873;
+pub const RTCFormat_RTC_FORMAT_FLOAT10: RTCFormat = 36874;
+pub const RTCFormat_RTC_FORMAT_FLOAT11: RTCFormat = 36875;
+pub const RTCFormat_RTC_FORMAT_FLOAT12: RTCFormat = 36876;
+pub const RTCFormat_RTC_FORMAT_FLOAT13: RTCFormat = 36877;
+pub const RTCFormat_RTC_FORMAT_FLOAT14: RTCFormat = 36878;
+pub const RTCFormat_RTC_FORMAT_FLOAT15: RTCFormat = 36879;
+pub const RTCFormat_RTC_FORMAT_FLOAT16: RTCFormat = 36880;
+pub const RTCFormat_RTC_FORMAT_FLOAT2X2_ROW_MAJOR: RTCFormat = 37154;
+pub const RTCFormat_RTC_FORMAT_FLOAT2X3_ROW_MAJOR: RTCFormat = 37155;
+pub const RTCFormat_RTC_FORMAT_FLOAT2X4_ROW_MAJOR: RTCFormat = 37156;
+pub const RTCFormat_RTC_FORMAT_FLOAT3X2_ROW_MAJOR: RTCFormat = 37170;
+pub const RTCFormat_RTC_FORMAT_FLOAT3X3_ROW_MAJOR: RTCFormat = 37171;
+pub const RTCFormat_RTC_FORMAT_FLOAT3X4_ROW_MAJOR: RTCFormat = 37172;
+pub const RTCFormat_RTC_FORMAT_FLOAT4X2_ROW_MAJOR: RTCFormat = 37186;
+pub const RTCFormat_RTC_FORMAT_FLOAT4X3_ROW_MAJOR: RTCFormat = 37187;
+pub const RTCFormat_RTC_FORMAT_FLOAT4X4_ROW_MAJOR: RTCFormat = 37188;
+pub const RTCFormat_RTC_FORMAT_FLOAT2X2_COLUMN_MAJOR: RTCFormat = 37410;
+pub const RTCFormat_RTC_FORMAT_FLOAT2X3_COLUMN_MAJOR: RTCFormat = 37411;
+pub const RTCFormat_RTC_FORMAT_FLOAT2X4_COLUMN_MAJOR: RTCFormat = 37412;
+pub const RTCFormat_RTC_FORMAT_FLOAT3X2_COLUMN_MAJOR: RTCFormat = 37426;
+pub const RTCFormat_RTC_FORMAT_FLOAT3X3_COLUMN_MAJOR: RTCFormat = 37427;
+pub const RTCFormat_RTC_FORMAT_FLOAT3X4_COLUMN_MAJOR: RTCFormat = 37428;
+pub const RTCFormat_RTC_FORMAT_FLOAT4X2_COLUMN_MAJOR: RTCFormat = 37442;
+pub const RTCFormat_RTC_FORMAT_FLOAT4X3_COLUMN_MAJOR: RTCFormat = 37443;
+pub const RTCFormat_RTC_FORMAT_FLOAT4X4_COLUMN_MAJOR: RTCFormat = 37444;
+pub const RTCFormat_RTC_FORMAT_GRID: RTCFormat = 40961;
+pub const RTCFormat_RTC_FORMAT_QUATERNION_DECOMPOSITION: RTCFormat = 45057;
+pub type RTCFormat = ::std::os::raw::c_uint;
+pub const RTCBuildQuality_RTC_BUILD_QUALITY_LOW: RTCBuildQuality = 0;
+pub const RTCBuildQuality_RTC_BUILD_QUALITY_MEDIUM: RTCBuildQuality = 1;
+pub const RTCBuildQuality_RTC_BUILD_QUALITY_HIGH: RTCBuildQuality = 2;
+pub const RTCBuildQuality_RTC_BUILD_QUALITY_REFIT: RTCBuildQuality = 3;
+pub type RTCBuildQuality = ::std::os::raw::c_uint;
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug)]
+pub struct RTCBounds {
+    pub lower_x: f32,
+    pub lower_y: f32,
+    pub lower_z: f32,
+    pub align0: f32,
+    pub upper_x: f32,
+    pub upper_y: f32,
+    pub upper_z: f32,
+    pub align1: f32,
+}
+#[test]
+fn bindgen_test_layout_RTCBounds() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCBounds>(),
+        32usize,
+        concat!("Size of: ", stringify!(RTCBounds))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCBounds>(),
+        16usize,
+        concat!("Alignment of ", stringify!(RTCBounds))
+    );
+    fn test_field_lower_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBounds>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).lower_x) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBounds),
+                "::",
+                stringify!(lower_x)
+            )
+        );
+    }
+    test_field_lower_x();
+    fn test_field_lower_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBounds>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).lower_y) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBounds),
+                "::",
+                stringify!(lower_y)
+            )
+        );
+    }
+    test_field_lower_y();
+    fn test_field_lower_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBounds>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).lower_z) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBounds),
+                "::",
+                stringify!(lower_z)
+            )
+        );
+    }
+    test_field_lower_z();
+    fn test_field_align0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBounds>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).align0) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBounds),
+                "::",
+                stringify!(align0)
+            )
+        );
+    }
+    test_field_align0();
+    fn test_field_upper_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBounds>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).upper_x) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBounds),
+                "::",
+                stringify!(upper_x)
+            )
+        );
+    }
+    test_field_upper_x();
+    fn test_field_upper_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBounds>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).upper_y) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBounds),
+                "::",
+                stringify!(upper_y)
+            )
+        );
+    }
+    test_field_upper_y();
+    fn test_field_upper_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBounds>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).upper_z) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBounds),
+                "::",
+                stringify!(upper_z)
+            )
+        );
+    }
+    test_field_upper_z();
+    fn test_field_align1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBounds>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).align1) as usize - ptr as usize
+            },
+            28usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBounds),
+                "::",
+                stringify!(align1)
+            )
+        );
+    }
+    test_field_align1();
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug)]
+pub struct RTCLinearBounds {
+    pub bounds0: RTCBounds,
+    pub bounds1: RTCBounds,
+}
+#[test]
+fn bindgen_test_layout_RTCLinearBounds() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCLinearBounds>(),
+        64usize,
+        concat!("Size of: ", stringify!(RTCLinearBounds))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCLinearBounds>(),
+        16usize,
+        concat!("Alignment of ", stringify!(RTCLinearBounds))
+    );
+    fn test_field_bounds0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCLinearBounds>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).bounds0) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCLinearBounds),
+                "::",
+                stringify!(bounds0)
+            )
+        );
+    }
+    test_field_bounds0();
+    fn test_field_bounds1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCLinearBounds>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).bounds1) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCLinearBounds),
+                "::",
+                stringify!(bounds1)
+            )
+        );
+    }
+    test_field_bounds1();
+}
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_NONE: RTCFeatureFlags = 0;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_MOTION_BLUR: RTCFeatureFlags = 1;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_TRIANGLE: RTCFeatureFlags = 2;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_QUAD: RTCFeatureFlags = 4;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_GRID: RTCFeatureFlags = 8;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_SUBDIVISION: RTCFeatureFlags = 16;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_CONE_LINEAR_CURVE: RTCFeatureFlags = 32;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_ROUND_LINEAR_CURVE: RTCFeatureFlags = 64;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_FLAT_LINEAR_CURVE: RTCFeatureFlags = 128;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_ROUND_BEZIER_CURVE: RTCFeatureFlags = 256;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_FLAT_BEZIER_CURVE: RTCFeatureFlags = 512;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_NORMAL_ORIENTED_BEZIER_CURVE: RTCFeatureFlags = 1024;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_ROUND_BSPLINE_CURVE: RTCFeatureFlags = 2048;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_FLAT_BSPLINE_CURVE: RTCFeatureFlags = 4096;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_NORMAL_ORIENTED_BSPLINE_CURVE: RTCFeatureFlags = 8192;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_ROUND_HERMITE_CURVE: RTCFeatureFlags = 16384;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_FLAT_HERMITE_CURVE: RTCFeatureFlags = 32768;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_NORMAL_ORIENTED_HERMITE_CURVE: RTCFeatureFlags = 65536;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_ROUND_CATMULL_ROM_CURVE: RTCFeatureFlags = 131072;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_FLAT_CATMULL_ROM_CURVE: RTCFeatureFlags = 262144;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_NORMAL_ORIENTED_CATMULL_ROM_CURVE: RTCFeatureFlags =
+    524288;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_SPHERE_POINT: RTCFeatureFlags = 1048576;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_DISC_POINT: RTCFeatureFlags = 2097152;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_ORIENTED_DISC_POINT: RTCFeatureFlags = 4194304;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_POINT: RTCFeatureFlags = 7340032;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_ROUND_CURVES: RTCFeatureFlags = 149824;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_FLAT_CURVES: RTCFeatureFlags = 299648;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_NORMAL_ORIENTED_CURVES: RTCFeatureFlags = 599040;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_LINEAR_CURVES: RTCFeatureFlags = 224;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_BEZIER_CURVES: RTCFeatureFlags = 1792;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_BSPLINE_CURVES: RTCFeatureFlags = 14336;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_HERMITE_CURVES: RTCFeatureFlags = 114688;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_CURVES: RTCFeatureFlags = 1048544;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_INSTANCE: RTCFeatureFlags = 8388608;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_FILTER_FUNCTION_IN_ARGUMENTS: RTCFeatureFlags = 16777216;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_FILTER_FUNCTION_IN_GEOMETRY: RTCFeatureFlags = 33554432;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_FILTER_FUNCTION: RTCFeatureFlags = 50331648;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_USER_GEOMETRY_CALLBACK_IN_ARGUMENTS: RTCFeatureFlags =
+    67108864;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_USER_GEOMETRY_CALLBACK_IN_GEOMETRY: RTCFeatureFlags =
+    134217728;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_USER_GEOMETRY: RTCFeatureFlags = 201326592;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_32_BIT_RAY_MASK: RTCFeatureFlags = 268435456;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_INSTANCE_ARRAY: RTCFeatureFlags = 536870912;
+pub const RTCFeatureFlags_RTC_FEATURE_FLAG_ALL: RTCFeatureFlags = 4294967295;
+pub type RTCFeatureFlags = ::std::os::raw::c_uint;
+pub const RTCRayQueryFlags_RTC_RAY_QUERY_FLAG_NONE: RTCRayQueryFlags = 0;
+pub const RTCRayQueryFlags_RTC_RAY_QUERY_FLAG_INVOKE_ARGUMENT_FILTER: RTCRayQueryFlags = 2;
+pub const RTCRayQueryFlags_RTC_RAY_QUERY_FLAG_INCOHERENT: RTCRayQueryFlags = 0;
+pub const RTCRayQueryFlags_RTC_RAY_QUERY_FLAG_COHERENT: RTCRayQueryFlags = 1;
+pub type RTCRayQueryFlags = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCRayQueryContext {
+    pub instID: [::std::os::raw::c_uint; 1usize],
+    pub instPrimID: [::std::os::raw::c_uint; 1usize],
+}
+#[test]
+fn bindgen_test_layout_RTCRayQueryContext() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCRayQueryContext>(),
+        8usize,
+        concat!("Size of: ", stringify!(RTCRayQueryContext))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCRayQueryContext>(),
+        4usize,
+        concat!("Alignment of ", stringify!(RTCRayQueryContext))
+    );
+    fn test_field_instID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRayQueryContext>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).instID) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRayQueryContext),
+                "::",
+                stringify!(instID)
+            )
+        );
+    }
+    test_field_instID();
+    fn test_field_instPrimID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRayQueryContext>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).instPrimID) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRayQueryContext),
+                "::",
+                stringify!(instPrimID)
+            )
+        );
+    }
+    test_field_instPrimID();
+}
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCRayN {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCHitN {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCFilterFunctionNArguments {
+    pub valid: *mut ::std::os::raw::c_int,
+    pub geometryUserPtr: *mut ::std::os::raw::c_void,
+    pub context: *mut RTCRayQueryContext,
+    pub ray: *mut RTCRayN,
+    pub hit: *mut RTCHitN,
+    pub N: ::std::os::raw::c_uint,
+}
+#[test]
+fn bindgen_test_layout_RTCFilterFunctionNArguments() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCFilterFunctionNArguments>(),
+        48usize,
+        concat!("Size of: ", stringify!(RTCFilterFunctionNArguments))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCFilterFunctionNArguments>(),
+        8usize,
+        concat!("Alignment of ", stringify!(RTCFilterFunctionNArguments))
+    );
+    fn test_field_valid() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCFilterFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).valid) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCFilterFunctionNArguments),
+                "::",
+                stringify!(valid)
+            )
+        );
+    }
+    test_field_valid();
+    fn test_field_geometryUserPtr() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCFilterFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).geometryUserPtr) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCFilterFunctionNArguments),
+                "::",
+                stringify!(geometryUserPtr)
+            )
+        );
+    }
+    test_field_geometryUserPtr();
+    fn test_field_context() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCFilterFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).context) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCFilterFunctionNArguments),
+                "::",
+                stringify!(context)
+            )
+        );
+    }
+    test_field_context();
+    fn test_field_ray() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCFilterFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ray) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCFilterFunctionNArguments),
+                "::",
+                stringify!(ray)
+            )
+        );
+    }
+    test_field_ray();
+    fn test_field_hit() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCFilterFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).hit) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCFilterFunctionNArguments),
+                "::",
+                stringify!(hit)
+            )
+        );
+    }
+    test_field_hit();
+    fn test_field_N() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCFilterFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).N) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCFilterFunctionNArguments),
+                "::",
+                stringify!(N)
+            )
+        );
+    }
+    test_field_N();
+}
+pub type RTCFilterFunctionN =
+    ::std::option::Option<unsafe extern "C" fn(args: *const RTCFilterFunctionNArguments)>;
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug)]
+pub struct RTCPointQuery {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub time: f32,
+    pub radius: f32,
+}
+#[test]
+fn bindgen_test_layout_RTCPointQuery() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCPointQuery>(),
+        32usize,
+        concat!("Size of: ", stringify!(RTCPointQuery))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCPointQuery>(),
+        16usize,
+        concat!("Alignment of ", stringify!(RTCPointQuery))
+    );
+    fn test_field_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQuery>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQuery),
+                "::",
+                stringify!(x)
+            )
+        );
+    }
+    test_field_x();
+    fn test_field_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQuery>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).y) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQuery),
+                "::",
+                stringify!(y)
+            )
+        );
+    }
+    test_field_y();
+    fn test_field_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQuery>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).z) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQuery),
+                "::",
+                stringify!(z)
+            )
+        );
+    }
+    test_field_z();
+    fn test_field_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQuery>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).time) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQuery),
+                "::",
+                stringify!(time)
+            )
+        );
+    }
+    test_field_time();
+    fn test_field_radius() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQuery>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).radius) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQuery),
+                "::",
+                stringify!(radius)
+            )
+        );
+    }
+    test_field_radius();
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug)]
+pub struct RTCPointQuery4 {
+    pub x: [f32; 4usize],
+    pub y: [f32; 4usize],
+    pub z: [f32; 4usize],
+    pub time: [f32; 4usize],
+    pub radius: [f32; 4usize],
+}
+#[test]
+fn bindgen_test_layout_RTCPointQuery4() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCPointQuery4>(),
+        80usize,
+        concat!("Size of: ", stringify!(RTCPointQuery4))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCPointQuery4>(),
+        16usize,
+        concat!("Alignment of ", stringify!(RTCPointQuery4))
+    );
+    fn test_field_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQuery4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQuery4),
+                "::",
+                stringify!(x)
+            )
+        );
+    }
+    test_field_x();
+    fn test_field_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQuery4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).y) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQuery4),
+                "::",
+                stringify!(y)
+            )
+        );
+    }
+    test_field_y();
+    fn test_field_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQuery4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).z) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQuery4),
+                "::",
+                stringify!(z)
+            )
+        );
+    }
+    test_field_z();
+    fn test_field_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQuery4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).time) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQuery4),
+                "::",
+                stringify!(time)
+            )
+        );
+    }
+    test_field_time();
+    fn test_field_radius() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQuery4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).radius) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQuery4),
+                "::",
+                stringify!(radius)
+            )
+        );
+    }
+    test_field_radius();
+}
+#[repr(C)]
+#[repr(align(32))]
+#[derive(Debug)]
+pub struct RTCPointQuery8 {
+    pub x: [f32; 8usize],
+    pub y: [f32; 8usize],
+    pub z: [f32; 8usize],
+    pub time: [f32; 8usize],
+    pub radius: [f32; 8usize],
+}
+#[test]
+fn bindgen_test_layout_RTCPointQuery8() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCPointQuery8>(),
+        160usize,
+        concat!("Size of: ", stringify!(RTCPointQuery8))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCPointQuery8>(),
+        32usize,
+        concat!("Alignment of ", stringify!(RTCPointQuery8))
+    );
+    fn test_field_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQuery8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQuery8),
+                "::",
+                stringify!(x)
+            )
+        );
+    }
+    test_field_x();
+    fn test_field_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQuery8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).y) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQuery8),
+                "::",
+                stringify!(y)
+            )
+        );
+    }
+    test_field_y();
+    fn test_field_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQuery8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).z) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQuery8),
+                "::",
+                stringify!(z)
+            )
+        );
+    }
+    test_field_z();
+    fn test_field_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQuery8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).time) as usize - ptr as usize
+            },
+            96usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQuery8),
+                "::",
+                stringify!(time)
+            )
+        );
+    }
+    test_field_time();
+    fn test_field_radius() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQuery8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).radius) as usize - ptr as usize
+            },
+            128usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQuery8),
+                "::",
+                stringify!(radius)
+            )
+        );
+    }
+    test_field_radius();
+}
+#[repr(C)]
+#[repr(align(64))]
+#[derive(Debug)]
+pub struct RTCPointQuery16 {
+    pub x: [f32; 16usize],
+    pub y: [f32; 16usize],
+    pub z: [f32; 16usize],
+    pub time: [f32; 16usize],
+    pub radius: [f32; 16usize],
+}
+#[test]
+fn bindgen_test_layout_RTCPointQuery16() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCPointQuery16>(),
+        320usize,
+        concat!("Size of: ", stringify!(RTCPointQuery16))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCPointQuery16>(),
+        64usize,
+        concat!("Alignment of ", stringify!(RTCPointQuery16))
+    );
+    fn test_field_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQuery16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQuery16),
+                "::",
+                stringify!(x)
+            )
+        );
+    }
+    test_field_x();
+    fn test_field_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQuery16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).y) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQuery16),
+                "::",
+                stringify!(y)
+            )
+        );
+    }
+    test_field_y();
+    fn test_field_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQuery16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).z) as usize - ptr as usize
+            },
+            128usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQuery16),
+                "::",
+                stringify!(z)
+            )
+        );
+    }
+    test_field_z();
+    fn test_field_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQuery16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).time) as usize - ptr as usize
+            },
+            192usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQuery16),
+                "::",
+                stringify!(time)
+            )
+        );
+    }
+    test_field_time();
+    fn test_field_radius() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQuery16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).radius) as usize - ptr as usize
+            },
+            256usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQuery16),
+                "::",
+                stringify!(radius)
+            )
+        );
+    }
+    test_field_radius();
+}
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCPointQueryN {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug)]
+pub struct RTCPointQueryContext {
+    pub world2inst: [[f32; 16usize]; 1usize],
+    pub inst2world: [[f32; 16usize]; 1usize],
+    pub instID: [::std::os::raw::c_uint; 1usize],
+    pub instPrimID: [::std::os::raw::c_uint; 1usize],
+    pub instStackSize: ::std::os::raw::c_uint,
+}
+#[test]
+fn bindgen_test_layout_RTCPointQueryContext() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCPointQueryContext>(),
+        144usize,
+        concat!("Size of: ", stringify!(RTCPointQueryContext))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCPointQueryContext>(),
+        16usize,
+        concat!("Alignment of ", stringify!(RTCPointQueryContext))
+    );
+    fn test_field_world2inst() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQueryContext>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).world2inst) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQueryContext),
+                "::",
+                stringify!(world2inst)
+            )
+        );
+    }
+    test_field_world2inst();
+    fn test_field_inst2world() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQueryContext>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).inst2world) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQueryContext),
+                "::",
+                stringify!(inst2world)
+            )
+        );
+    }
+    test_field_inst2world();
+    fn test_field_instID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQueryContext>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).instID) as usize - ptr as usize
+            },
+            128usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQueryContext),
+                "::",
+                stringify!(instID)
+            )
+        );
+    }
+    test_field_instID();
+    fn test_field_instPrimID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQueryContext>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).instPrimID) as usize - ptr as usize
+            },
+            132usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQueryContext),
+                "::",
+                stringify!(instPrimID)
+            )
+        );
+    }
+    test_field_instPrimID();
+    fn test_field_instStackSize() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQueryContext>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).instStackSize) as usize - ptr as usize
+            },
+            136usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQueryContext),
+                "::",
+                stringify!(instStackSize)
+            )
+        );
+    }
+    test_field_instStackSize();
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug)]
+pub struct RTCPointQueryFunctionArguments {
+    pub query: *mut RTCPointQuery,
+    pub userPtr: *mut ::std::os::raw::c_void,
+    pub primID: ::std::os::raw::c_uint,
+    pub geomID: ::std::os::raw::c_uint,
+    pub context: *mut RTCPointQueryContext,
+    pub similarityScale: f32,
+}
+#[test]
+fn bindgen_test_layout_RTCPointQueryFunctionArguments() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCPointQueryFunctionArguments>(),
+        48usize,
+        concat!("Size of: ", stringify!(RTCPointQueryFunctionArguments))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCPointQueryFunctionArguments>(),
+        16usize,
+        concat!("Alignment of ", stringify!(RTCPointQueryFunctionArguments))
+    );
+    fn test_field_query() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQueryFunctionArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).query) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQueryFunctionArguments),
+                "::",
+                stringify!(query)
+            )
+        );
+    }
+    test_field_query();
+    fn test_field_userPtr() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQueryFunctionArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).userPtr) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQueryFunctionArguments),
+                "::",
+                stringify!(userPtr)
+            )
+        );
+    }
+    test_field_userPtr();
+    fn test_field_primID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQueryFunctionArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).primID) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQueryFunctionArguments),
+                "::",
+                stringify!(primID)
+            )
+        );
+    }
+    test_field_primID();
+    fn test_field_geomID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQueryFunctionArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).geomID) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQueryFunctionArguments),
+                "::",
+                stringify!(geomID)
+            )
+        );
+    }
+    test_field_geomID();
+    fn test_field_context() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQueryFunctionArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).context) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQueryFunctionArguments),
+                "::",
+                stringify!(context)
+            )
+        );
+    }
+    test_field_context();
+    fn test_field_similarityScale() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCPointQueryFunctionArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).similarityScale) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCPointQueryFunctionArguments),
+                "::",
+                stringify!(similarityScale)
+            )
+        );
+    }
+    test_field_similarityScale();
+}
+pub type RTCPointQueryFunction =
+    ::std::option::Option<unsafe extern "C" fn(args: *mut RTCPointQueryFunctionArguments) -> bool>;
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCDeviceTy {
+    _unused: [u8; 0],
+}
+pub type RTCDevice = *mut RTCDeviceTy;
+extern "C" {
+    pub fn rtcNewDevice(config: *const ::std::os::raw::c_char) -> RTCDevice;
+}
+extern "C" {
+    pub fn rtcRetainDevice(device: RTCDevice);
+}
+extern "C" {
+    pub fn rtcReleaseDevice(device: RTCDevice);
+}
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_VERSION: RTCDeviceProperty = 0;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_VERSION_MAJOR: RTCDeviceProperty = 1;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_VERSION_MINOR: RTCDeviceProperty = 2;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_VERSION_PATCH: RTCDeviceProperty = 3;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_NATIVE_RAY4_SUPPORTED: RTCDeviceProperty = 32;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_NATIVE_RAY8_SUPPORTED: RTCDeviceProperty = 33;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_NATIVE_RAY16_SUPPORTED: RTCDeviceProperty = 34;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_RAY_STREAM_SUPPORTED: RTCDeviceProperty = 35;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_BACKFACE_CULLING_CURVES_ENABLED: RTCDeviceProperty =
+    63;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_RAY_MASK_SUPPORTED: RTCDeviceProperty = 64;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_BACKFACE_CULLING_ENABLED: RTCDeviceProperty = 65;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_FILTER_FUNCTION_SUPPORTED: RTCDeviceProperty = 66;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_IGNORE_INVALID_RAYS_ENABLED: RTCDeviceProperty = 67;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_COMPACT_POLYS_ENABLED: RTCDeviceProperty = 68;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_TRIANGLE_GEOMETRY_SUPPORTED: RTCDeviceProperty = 96;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_QUAD_GEOMETRY_SUPPORTED: RTCDeviceProperty = 97;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_SUBDIVISION_GEOMETRY_SUPPORTED: RTCDeviceProperty =
+    98;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_CURVE_GEOMETRY_SUPPORTED: RTCDeviceProperty = 99;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_USER_GEOMETRY_SUPPORTED: RTCDeviceProperty = 100;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_POINT_GEOMETRY_SUPPORTED: RTCDeviceProperty = 101;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_TASKING_SYSTEM: RTCDeviceProperty = 128;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_JOIN_COMMIT_SUPPORTED: RTCDeviceProperty = 129;
+pub const RTCDeviceProperty_RTC_DEVICE_PROPERTY_PARALLEL_COMMIT_SUPPORTED: RTCDeviceProperty = 130;
+pub type RTCDeviceProperty = ::std::os::raw::c_uint;
+extern "C" {
+    pub fn rtcGetDeviceProperty(device: RTCDevice, prop: RTCDeviceProperty) -> isize;
+}
+extern "C" {
+    pub fn rtcSetDeviceProperty(device: RTCDevice, prop: RTCDeviceProperty, value: isize);
+}
+pub const RTCError_RTC_ERROR_NONE: RTCError = 0;
+pub const RTCError_RTC_ERROR_UNKNOWN: RTCError = 1;
+pub const RTCError_RTC_ERROR_INVALID_ARGUMENT: RTCError = 2;
+pub const RTCError_RTC_ERROR_INVALID_OPERATION: RTCError = 3;
+pub const RTCError_RTC_ERROR_OUT_OF_MEMORY: RTCError = 4;
+pub const RTCError_RTC_ERROR_UNSUPPORTED_CPU: RTCError = 5;
+pub const RTCError_RTC_ERROR_CANCELLED: RTCError = 6;
+pub const RTCError_RTC_ERROR_LEVEL_ZERO_RAYTRACING_SUPPORT_MISSING: RTCError = 7;
+pub type RTCError = ::std::os::raw::c_uint;
+extern "C" {
+    pub fn rtcGetDeviceError(device: RTCDevice) -> RTCError;
+}
+extern "C" {
+    pub fn rtcGetErrorString(error: RTCError) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn rtcGetDeviceLastErrorMessage(device: RTCDevice) -> *const ::std::os::raw::c_char;
+}
+pub type RTCErrorFunction = ::std::option::Option<
+    unsafe extern "C" fn(
+        userPtr: *mut ::std::os::raw::c_void,
+        code: RTCError,
+        str: *const ::std::os::raw::c_char,
+    ),
+>;
+extern "C" {
+    pub fn rtcSetDeviceErrorFunction(
+        device: RTCDevice,
+        error: RTCErrorFunction,
+        userPtr: *mut ::std::os::raw::c_void,
+    );
+}
+pub type RTCMemoryMonitorFunction = ::std::option::Option<
+    unsafe extern "C" fn(ptr: *mut ::std::os::raw::c_void, bytes: isize, post: bool) -> bool,
+>;
+extern "C" {
+    pub fn rtcSetDeviceMemoryMonitorFunction(
+        device: RTCDevice,
+        memoryMonitor: RTCMemoryMonitorFunction,
+        userPtr: *mut ::std::os::raw::c_void,
+    );
+}
+pub const RTCBufferType_RTC_BUFFER_TYPE_INDEX: RTCBufferType = 0;
+pub const RTCBufferType_RTC_BUFFER_TYPE_VERTEX: RTCBufferType = 1;
+pub const RTCBufferType_RTC_BUFFER_TYPE_VERTEX_ATTRIBUTE: RTCBufferType = 2;
+pub const RTCBufferType_RTC_BUFFER_TYPE_NORMAL: RTCBufferType = 3;
+pub const RTCBufferType_RTC_BUFFER_TYPE_TANGENT: RTCBufferType = 4;
+pub const RTCBufferType_RTC_BUFFER_TYPE_NORMAL_DERIVATIVE: RTCBufferType = 5;
+pub const RTCBufferType_RTC_BUFFER_TYPE_GRID: RTCBufferType = 8;
+pub const RTCBufferType_RTC_BUFFER_TYPE_FACE: RTCBufferType = 16;
+pub const RTCBufferType_RTC_BUFFER_TYPE_LEVEL: RTCBufferType = 17;
+pub const RTCBufferType_RTC_BUFFER_TYPE_EDGE_CREASE_INDEX: RTCBufferType = 18;
+pub const RTCBufferType_RTC_BUFFER_TYPE_EDGE_CREASE_WEIGHT: RTCBufferType = 19;
+pub const RTCBufferType_RTC_BUFFER_TYPE_VERTEX_CREASE_INDEX: RTCBufferType = 20;
+pub const RTCBufferType_RTC_BUFFER_TYPE_VERTEX_CREASE_WEIGHT: RTCBufferType = 21;
+pub const RTCBufferType_RTC_BUFFER_TYPE_HOLE: RTCBufferType = 22;
+pub const RTCBufferType_RTC_BUFFER_TYPE_FLAGS: RTCBufferType = 32;
+pub type RTCBufferType = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCBufferTy {
+    _unused: [u8; 0],
+}
+pub type RTCBuffer = *mut RTCBufferTy;
+extern "C" {
+    pub fn rtcNewBuffer(device: RTCDevice, byteSize: usize) -> RTCBuffer;
+}
+extern "C" {
+    pub fn rtcNewSharedBuffer(
+        device: RTCDevice,
+        ptr: *mut ::std::os::raw::c_void,
+        byteSize: usize,
+    ) -> RTCBuffer;
+}
+extern "C" {
+    pub fn rtcGetBufferData(buffer: RTCBuffer) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn rtcRetainBuffer(buffer: RTCBuffer);
+}
+extern "C" {
+    pub fn rtcReleaseBuffer(buffer: RTCBuffer);
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug)]
+pub struct RTCRay {
+    pub org_x: f32,
+    pub org_y: f32,
+    pub org_z: f32,
+    pub tnear: f32,
+    pub dir_x: f32,
+    pub dir_y: f32,
+    pub dir_z: f32,
+    pub time: f32,
+    pub tfar: f32,
+    pub mask: ::std::os::raw::c_uint,
+    pub id: ::std::os::raw::c_uint,
+    pub flags: ::std::os::raw::c_uint,
+}
+#[test]
+fn bindgen_test_layout_RTCRay() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCRay>(),
+        48usize,
+        concat!("Size of: ", stringify!(RTCRay))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCRay>(),
+        16usize,
+        concat!("Alignment of ", stringify!(RTCRay))
+    );
+    fn test_field_org_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).org_x) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay),
+                "::",
+                stringify!(org_x)
+            )
+        );
+    }
+    test_field_org_x();
+    fn test_field_org_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).org_y) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay),
+                "::",
+                stringify!(org_y)
+            )
+        );
+    }
+    test_field_org_y();
+    fn test_field_org_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).org_z) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay),
+                "::",
+                stringify!(org_z)
+            )
+        );
+    }
+    test_field_org_z();
+    fn test_field_tnear() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).tnear) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay),
+                "::",
+                stringify!(tnear)
+            )
+        );
+    }
+    test_field_tnear();
+    fn test_field_dir_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).dir_x) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay),
+                "::",
+                stringify!(dir_x)
+            )
+        );
+    }
+    test_field_dir_x();
+    fn test_field_dir_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).dir_y) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay),
+                "::",
+                stringify!(dir_y)
+            )
+        );
+    }
+    test_field_dir_y();
+    fn test_field_dir_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).dir_z) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay),
+                "::",
+                stringify!(dir_z)
+            )
+        );
+    }
+    test_field_dir_z();
+    fn test_field_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).time) as usize - ptr as usize
+            },
+            28usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay),
+                "::",
+                stringify!(time)
+            )
+        );
+    }
+    test_field_time();
+    fn test_field_tfar() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).tfar) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay),
+                "::",
+                stringify!(tfar)
+            )
+        );
+    }
+    test_field_tfar();
+    fn test_field_mask() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).mask) as usize - ptr as usize
+            },
+            36usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay),
+                "::",
+                stringify!(mask)
+            )
+        );
+    }
+    test_field_mask();
+    fn test_field_id() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).id) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay),
+                "::",
+                stringify!(id)
+            )
+        );
+    }
+    test_field_id();
+    fn test_field_flags() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize
+            },
+            44usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay),
+                "::",
+                stringify!(flags)
+            )
+        );
+    }
+    test_field_flags();
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug)]
+pub struct RTCHit {
+    pub Ng_x: f32,
+    pub Ng_y: f32,
+    pub Ng_z: f32,
+    pub u: f32,
+    pub v: f32,
+    pub primID: ::std::os::raw::c_uint,
+    pub geomID: ::std::os::raw::c_uint,
+    pub instID: [::std::os::raw::c_uint; 1usize],
+    pub instPrimID: [::std::os::raw::c_uint; 1usize],
+}
+#[test]
+fn bindgen_test_layout_RTCHit() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCHit>(),
+        48usize,
+        concat!("Size of: ", stringify!(RTCHit))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCHit>(),
+        16usize,
+        concat!("Alignment of ", stringify!(RTCHit))
+    );
+    fn test_field_Ng_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).Ng_x) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit),
+                "::",
+                stringify!(Ng_x)
+            )
+        );
+    }
+    test_field_Ng_x();
+    fn test_field_Ng_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).Ng_y) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit),
+                "::",
+                stringify!(Ng_y)
+            )
+        );
+    }
+    test_field_Ng_y();
+    fn test_field_Ng_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).Ng_z) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit),
+                "::",
+                stringify!(Ng_z)
+            )
+        );
+    }
+    test_field_Ng_z();
+    fn test_field_u() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).u) as usize - ptr as usize
+            },
+            12usize,
+            concat!("Offset of field: ", stringify!(RTCHit), "::", stringify!(u))
+        );
+    }
+    test_field_u();
+    fn test_field_v() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).v) as usize - ptr as usize
+            },
+            16usize,
+            concat!("Offset of field: ", stringify!(RTCHit), "::", stringify!(v))
+        );
+    }
+    test_field_v();
+    fn test_field_primID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).primID) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit),
+                "::",
+                stringify!(primID)
+            )
+        );
+    }
+    test_field_primID();
+    fn test_field_geomID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).geomID) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit),
+                "::",
+                stringify!(geomID)
+            )
+        );
+    }
+    test_field_geomID();
+    fn test_field_instID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).instID) as usize - ptr as usize
+            },
+            28usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit),
+                "::",
+                stringify!(instID)
+            )
+        );
+    }
+    test_field_instID();
+    fn test_field_instPrimID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).instPrimID) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit),
+                "::",
+                stringify!(instPrimID)
+            )
+        );
+    }
+    test_field_instPrimID();
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug)]
+pub struct RTCRayHit {
+    pub ray: RTCRay,
+    pub hit: RTCHit,
+}
+#[test]
+fn bindgen_test_layout_RTCRayHit() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCRayHit>(),
+        96usize,
+        concat!("Size of: ", stringify!(RTCRayHit))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCRayHit>(),
+        16usize,
+        concat!("Alignment of ", stringify!(RTCRayHit))
+    );
+    fn test_field_ray() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRayHit>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ray) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRayHit),
+                "::",
+                stringify!(ray)
+            )
+        );
+    }
+    test_field_ray();
+    fn test_field_hit() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRayHit>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).hit) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRayHit),
+                "::",
+                stringify!(hit)
+            )
+        );
+    }
+    test_field_hit();
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug)]
+pub struct RTCRay4 {
+    pub org_x: [f32; 4usize],
+    pub org_y: [f32; 4usize],
+    pub org_z: [f32; 4usize],
+    pub tnear: [f32; 4usize],
+    pub dir_x: [f32; 4usize],
+    pub dir_y: [f32; 4usize],
+    pub dir_z: [f32; 4usize],
+    pub time: [f32; 4usize],
+    pub tfar: [f32; 4usize],
+    pub mask: [::std::os::raw::c_uint; 4usize],
+    pub id: [::std::os::raw::c_uint; 4usize],
+    pub flags: [::std::os::raw::c_uint; 4usize],
+}
+#[test]
+fn bindgen_test_layout_RTCRay4() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCRay4>(),
+        192usize,
+        concat!("Size of: ", stringify!(RTCRay4))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCRay4>(),
+        16usize,
+        concat!("Alignment of ", stringify!(RTCRay4))
+    );
+    fn test_field_org_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).org_x) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay4),
+                "::",
+                stringify!(org_x)
+            )
+        );
+    }
+    test_field_org_x();
+    fn test_field_org_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).org_y) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay4),
+                "::",
+                stringify!(org_y)
+            )
+        );
+    }
+    test_field_org_y();
+    fn test_field_org_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).org_z) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay4),
+                "::",
+                stringify!(org_z)
+            )
+        );
+    }
+    test_field_org_z();
+    fn test_field_tnear() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).tnear) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay4),
+                "::",
+                stringify!(tnear)
+            )
+        );
+    }
+    test_field_tnear();
+    fn test_field_dir_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).dir_x) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay4),
+                "::",
+                stringify!(dir_x)
+            )
+        );
+    }
+    test_field_dir_x();
+    fn test_field_dir_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).dir_y) as usize - ptr as usize
+            },
+            80usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay4),
+                "::",
+                stringify!(dir_y)
+            )
+        );
+    }
+    test_field_dir_y();
+    fn test_field_dir_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).dir_z) as usize - ptr as usize
+            },
+            96usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay4),
+                "::",
+                stringify!(dir_z)
+            )
+        );
+    }
+    test_field_dir_z();
+    fn test_field_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).time) as usize - ptr as usize
+            },
+            112usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay4),
+                "::",
+                stringify!(time)
+            )
+        );
+    }
+    test_field_time();
+    fn test_field_tfar() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).tfar) as usize - ptr as usize
+            },
+            128usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay4),
+                "::",
+                stringify!(tfar)
+            )
+        );
+    }
+    test_field_tfar();
+    fn test_field_mask() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).mask) as usize - ptr as usize
+            },
+            144usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay4),
+                "::",
+                stringify!(mask)
+            )
+        );
+    }
+    test_field_mask();
+    fn test_field_id() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).id) as usize - ptr as usize
+            },
+            160usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay4),
+                "::",
+                stringify!(id)
+            )
+        );
+    }
+    test_field_id();
+    fn test_field_flags() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize
+            },
+            176usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay4),
+                "::",
+                stringify!(flags)
+            )
+        );
+    }
+    test_field_flags();
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug)]
+pub struct RTCHit4 {
+    pub Ng_x: [f32; 4usize],
+    pub Ng_y: [f32; 4usize],
+    pub Ng_z: [f32; 4usize],
+    pub u: [f32; 4usize],
+    pub v: [f32; 4usize],
+    pub primID: [::std::os::raw::c_uint; 4usize],
+    pub geomID: [::std::os::raw::c_uint; 4usize],
+    pub instID: [[::std::os::raw::c_uint; 4usize]; 1usize],
+    pub instPrimID: [[::std::os::raw::c_uint; 4usize]; 1usize],
+}
+#[test]
+fn bindgen_test_layout_RTCHit4() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCHit4>(),
+        144usize,
+        concat!("Size of: ", stringify!(RTCHit4))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCHit4>(),
+        16usize,
+        concat!("Alignment of ", stringify!(RTCHit4))
+    );
+    fn test_field_Ng_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).Ng_x) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit4),
+                "::",
+                stringify!(Ng_x)
+            )
+        );
+    }
+    test_field_Ng_x();
+    fn test_field_Ng_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).Ng_y) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit4),
+                "::",
+                stringify!(Ng_y)
+            )
+        );
+    }
+    test_field_Ng_y();
+    fn test_field_Ng_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).Ng_z) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit4),
+                "::",
+                stringify!(Ng_z)
+            )
+        );
+    }
+    test_field_Ng_z();
+    fn test_field_u() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).u) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit4),
+                "::",
+                stringify!(u)
+            )
+        );
+    }
+    test_field_u();
+    fn test_field_v() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).v) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit4),
+                "::",
+                stringify!(v)
+            )
+        );
+    }
+    test_field_v();
+    fn test_field_primID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).primID) as usize - ptr as usize
+            },
+            80usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit4),
+                "::",
+                stringify!(primID)
+            )
+        );
+    }
+    test_field_primID();
+    fn test_field_geomID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).geomID) as usize - ptr as usize
+            },
+            96usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit4),
+                "::",
+                stringify!(geomID)
+            )
+        );
+    }
+    test_field_geomID();
+    fn test_field_instID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).instID) as usize - ptr as usize
+            },
+            112usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit4),
+                "::",
+                stringify!(instID)
+            )
+        );
+    }
+    test_field_instID();
+    fn test_field_instPrimID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).instPrimID) as usize - ptr as usize
+            },
+            128usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit4),
+                "::",
+                stringify!(instPrimID)
+            )
+        );
+    }
+    test_field_instPrimID();
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug)]
+pub struct RTCRayHit4 {
+    pub ray: RTCRay4,
+    pub hit: RTCHit4,
+}
+#[test]
+fn bindgen_test_layout_RTCRayHit4() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCRayHit4>(),
+        336usize,
+        concat!("Size of: ", stringify!(RTCRayHit4))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCRayHit4>(),
+        16usize,
+        concat!("Alignment of ", stringify!(RTCRayHit4))
+    );
+    fn test_field_ray() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRayHit4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ray) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRayHit4),
+                "::",
+                stringify!(ray)
+            )
+        );
+    }
+    test_field_ray();
+    fn test_field_hit() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRayHit4>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).hit) as usize - ptr as usize
+            },
+            192usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRayHit4),
+                "::",
+                stringify!(hit)
+            )
+        );
+    }
+    test_field_hit();
+}
+#[repr(C)]
+#[repr(align(32))]
+#[derive(Debug)]
+pub struct RTCRay8 {
+    pub org_x: [f32; 8usize],
+    pub org_y: [f32; 8usize],
+    pub org_z: [f32; 8usize],
+    pub tnear: [f32; 8usize],
+    pub dir_x: [f32; 8usize],
+    pub dir_y: [f32; 8usize],
+    pub dir_z: [f32; 8usize],
+    pub time: [f32; 8usize],
+    pub tfar: [f32; 8usize],
+    pub mask: [::std::os::raw::c_uint; 8usize],
+    pub id: [::std::os::raw::c_uint; 8usize],
+    pub flags: [::std::os::raw::c_uint; 8usize],
+}
+#[test]
+fn bindgen_test_layout_RTCRay8() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCRay8>(),
+        384usize,
+        concat!("Size of: ", stringify!(RTCRay8))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCRay8>(),
+        32usize,
+        concat!("Alignment of ", stringify!(RTCRay8))
+    );
+    fn test_field_org_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).org_x) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay8),
+                "::",
+                stringify!(org_x)
+            )
+        );
+    }
+    test_field_org_x();
+    fn test_field_org_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).org_y) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay8),
+                "::",
+                stringify!(org_y)
+            )
+        );
+    }
+    test_field_org_y();
+    fn test_field_org_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).org_z) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay8),
+                "::",
+                stringify!(org_z)
+            )
+        );
+    }
+    test_field_org_z();
+    fn test_field_tnear() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).tnear) as usize - ptr as usize
+            },
+            96usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay8),
+                "::",
+                stringify!(tnear)
+            )
+        );
+    }
+    test_field_tnear();
+    fn test_field_dir_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).dir_x) as usize - ptr as usize
+            },
+            128usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay8),
+                "::",
+                stringify!(dir_x)
+            )
+        );
+    }
+    test_field_dir_x();
+    fn test_field_dir_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).dir_y) as usize - ptr as usize
+            },
+            160usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay8),
+                "::",
+                stringify!(dir_y)
+            )
+        );
+    }
+    test_field_dir_y();
+    fn test_field_dir_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).dir_z) as usize - ptr as usize
+            },
+            192usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay8),
+                "::",
+                stringify!(dir_z)
+            )
+        );
+    }
+    test_field_dir_z();
+    fn test_field_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).time) as usize - ptr as usize
+            },
+            224usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay8),
+                "::",
+                stringify!(time)
+            )
+        );
+    }
+    test_field_time();
+    fn test_field_tfar() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).tfar) as usize - ptr as usize
+            },
+            256usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay8),
+                "::",
+                stringify!(tfar)
+            )
+        );
+    }
+    test_field_tfar();
+    fn test_field_mask() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).mask) as usize - ptr as usize
+            },
+            288usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay8),
+                "::",
+                stringify!(mask)
+            )
+        );
+    }
+    test_field_mask();
+    fn test_field_id() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).id) as usize - ptr as usize
+            },
+            320usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay8),
+                "::",
+                stringify!(id)
+            )
+        );
+    }
+    test_field_id();
+    fn test_field_flags() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize
+            },
+            352usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay8),
+                "::",
+                stringify!(flags)
+            )
+        );
+    }
+    test_field_flags();
+}
+#[repr(C)]
+#[repr(align(32))]
+#[derive(Debug)]
+pub struct RTCHit8 {
+    pub Ng_x: [f32; 8usize],
+    pub Ng_y: [f32; 8usize],
+    pub Ng_z: [f32; 8usize],
+    pub u: [f32; 8usize],
+    pub v: [f32; 8usize],
+    pub primID: [::std::os::raw::c_uint; 8usize],
+    pub geomID: [::std::os::raw::c_uint; 8usize],
+    pub instID: [[::std::os::raw::c_uint; 8usize]; 1usize],
+    pub instPrimID: [[::std::os::raw::c_uint; 8usize]; 1usize],
+}
+#[test]
+fn bindgen_test_layout_RTCHit8() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCHit8>(),
+        288usize,
+        concat!("Size of: ", stringify!(RTCHit8))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCHit8>(),
+        32usize,
+        concat!("Alignment of ", stringify!(RTCHit8))
+    );
+    fn test_field_Ng_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).Ng_x) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit8),
+                "::",
+                stringify!(Ng_x)
+            )
+        );
+    }
+    test_field_Ng_x();
+    fn test_field_Ng_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).Ng_y) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit8),
+                "::",
+                stringify!(Ng_y)
+            )
+        );
+    }
+    test_field_Ng_y();
+    fn test_field_Ng_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).Ng_z) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit8),
+                "::",
+                stringify!(Ng_z)
+            )
+        );
+    }
+    test_field_Ng_z();
+    fn test_field_u() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).u) as usize - ptr as usize
+            },
+            96usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit8),
+                "::",
+                stringify!(u)
+            )
+        );
+    }
+    test_field_u();
+    fn test_field_v() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).v) as usize - ptr as usize
+            },
+            128usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit8),
+                "::",
+                stringify!(v)
+            )
+        );
+    }
+    test_field_v();
+    fn test_field_primID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).primID) as usize - ptr as usize
+            },
+            160usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit8),
+                "::",
+                stringify!(primID)
+            )
+        );
+    }
+    test_field_primID();
+    fn test_field_geomID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).geomID) as usize - ptr as usize
+            },
+            192usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit8),
+                "::",
+                stringify!(geomID)
+            )
+        );
+    }
+    test_field_geomID();
+    fn test_field_instID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).instID) as usize - ptr as usize
+            },
+            224usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit8),
+                "::",
+                stringify!(instID)
+            )
+        );
+    }
+    test_field_instID();
+    fn test_field_instPrimID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).instPrimID) as usize - ptr as usize
+            },
+            256usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit8),
+                "::",
+                stringify!(instPrimID)
+            )
+        );
+    }
+    test_field_instPrimID();
+}
+#[repr(C)]
+#[repr(align(32))]
+#[derive(Debug)]
+pub struct RTCRayHit8 {
+    pub ray: RTCRay8,
+    pub hit: RTCHit8,
+}
+#[test]
+fn bindgen_test_layout_RTCRayHit8() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCRayHit8>(),
+        672usize,
+        concat!("Size of: ", stringify!(RTCRayHit8))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCRayHit8>(),
+        32usize,
+        concat!("Alignment of ", stringify!(RTCRayHit8))
+    );
+    fn test_field_ray() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRayHit8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ray) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRayHit8),
+                "::",
+                stringify!(ray)
+            )
+        );
+    }
+    test_field_ray();
+    fn test_field_hit() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRayHit8>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).hit) as usize - ptr as usize
+            },
+            384usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRayHit8),
+                "::",
+                stringify!(hit)
+            )
+        );
+    }
+    test_field_hit();
+}
+#[repr(C)]
+#[repr(align(64))]
+#[derive(Debug)]
+pub struct RTCRay16 {
+    pub org_x: [f32; 16usize],
+    pub org_y: [f32; 16usize],
+    pub org_z: [f32; 16usize],
+    pub tnear: [f32; 16usize],
+    pub dir_x: [f32; 16usize],
+    pub dir_y: [f32; 16usize],
+    pub dir_z: [f32; 16usize],
+    pub time: [f32; 16usize],
+    pub tfar: [f32; 16usize],
+    pub mask: [::std::os::raw::c_uint; 16usize],
+    pub id: [::std::os::raw::c_uint; 16usize],
+    pub flags: [::std::os::raw::c_uint; 16usize],
+}
+#[test]
+fn bindgen_test_layout_RTCRay16() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCRay16>(),
+        768usize,
+        concat!("Size of: ", stringify!(RTCRay16))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCRay16>(),
+        64usize,
+        concat!("Alignment of ", stringify!(RTCRay16))
+    );
+    fn test_field_org_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).org_x) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay16),
+                "::",
+                stringify!(org_x)
+            )
+        );
+    }
+    test_field_org_x();
+    fn test_field_org_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).org_y) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay16),
+                "::",
+                stringify!(org_y)
+            )
+        );
+    }
+    test_field_org_y();
+    fn test_field_org_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).org_z) as usize - ptr as usize
+            },
+            128usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay16),
+                "::",
+                stringify!(org_z)
+            )
+        );
+    }
+    test_field_org_z();
+    fn test_field_tnear() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).tnear) as usize - ptr as usize
+            },
+            192usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay16),
+                "::",
+                stringify!(tnear)
+            )
+        );
+    }
+    test_field_tnear();
+    fn test_field_dir_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).dir_x) as usize - ptr as usize
+            },
+            256usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay16),
+                "::",
+                stringify!(dir_x)
+            )
+        );
+    }
+    test_field_dir_x();
+    fn test_field_dir_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).dir_y) as usize - ptr as usize
+            },
+            320usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay16),
+                "::",
+                stringify!(dir_y)
+            )
+        );
+    }
+    test_field_dir_y();
+    fn test_field_dir_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).dir_z) as usize - ptr as usize
+            },
+            384usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay16),
+                "::",
+                stringify!(dir_z)
+            )
+        );
+    }
+    test_field_dir_z();
+    fn test_field_time() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).time) as usize - ptr as usize
+            },
+            448usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay16),
+                "::",
+                stringify!(time)
+            )
+        );
+    }
+    test_field_time();
+    fn test_field_tfar() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).tfar) as usize - ptr as usize
+            },
+            512usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay16),
+                "::",
+                stringify!(tfar)
+            )
+        );
+    }
+    test_field_tfar();
+    fn test_field_mask() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).mask) as usize - ptr as usize
+            },
+            576usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay16),
+                "::",
+                stringify!(mask)
+            )
+        );
+    }
+    test_field_mask();
+    fn test_field_id() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).id) as usize - ptr as usize
+            },
+            640usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay16),
+                "::",
+                stringify!(id)
+            )
+        );
+    }
+    test_field_id();
+    fn test_field_flags() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRay16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize
+            },
+            704usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRay16),
+                "::",
+                stringify!(flags)
+            )
+        );
+    }
+    test_field_flags();
+}
+#[repr(C)]
+#[repr(align(64))]
+#[derive(Debug)]
+pub struct RTCHit16 {
+    pub Ng_x: [f32; 16usize],
+    pub Ng_y: [f32; 16usize],
+    pub Ng_z: [f32; 16usize],
+    pub u: [f32; 16usize],
+    pub v: [f32; 16usize],
+    pub primID: [::std::os::raw::c_uint; 16usize],
+    pub geomID: [::std::os::raw::c_uint; 16usize],
+    pub instID: [[::std::os::raw::c_uint; 16usize]; 1usize],
+    pub instPrimID: [[::std::os::raw::c_uint; 16usize]; 1usize],
+}
+#[test]
+fn bindgen_test_layout_RTCHit16() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCHit16>(),
+        576usize,
+        concat!("Size of: ", stringify!(RTCHit16))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCHit16>(),
+        64usize,
+        concat!("Alignment of ", stringify!(RTCHit16))
+    );
+    fn test_field_Ng_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).Ng_x) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit16),
+                "::",
+                stringify!(Ng_x)
+            )
+        );
+    }
+    test_field_Ng_x();
+    fn test_field_Ng_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).Ng_y) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit16),
+                "::",
+                stringify!(Ng_y)
+            )
+        );
+    }
+    test_field_Ng_y();
+    fn test_field_Ng_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).Ng_z) as usize - ptr as usize
+            },
+            128usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit16),
+                "::",
+                stringify!(Ng_z)
+            )
+        );
+    }
+    test_field_Ng_z();
+    fn test_field_u() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).u) as usize - ptr as usize
+            },
+            192usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit16),
+                "::",
+                stringify!(u)
+            )
+        );
+    }
+    test_field_u();
+    fn test_field_v() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).v) as usize - ptr as usize
+            },
+            256usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit16),
+                "::",
+                stringify!(v)
+            )
+        );
+    }
+    test_field_v();
+    fn test_field_primID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).primID) as usize - ptr as usize
+            },
+            320usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit16),
+                "::",
+                stringify!(primID)
+            )
+        );
+    }
+    test_field_primID();
+    fn test_field_geomID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).geomID) as usize - ptr as usize
+            },
+            384usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit16),
+                "::",
+                stringify!(geomID)
+            )
+        );
+    }
+    test_field_geomID();
+    fn test_field_instID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).instID) as usize - ptr as usize
+            },
+            448usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit16),
+                "::",
+                stringify!(instID)
+            )
+        );
+    }
+    test_field_instID();
+    fn test_field_instPrimID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCHit16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).instPrimID) as usize - ptr as usize
+            },
+            512usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCHit16),
+                "::",
+                stringify!(instPrimID)
+            )
+        );
+    }
+    test_field_instPrimID();
+}
+#[repr(C)]
+#[repr(align(64))]
+#[derive(Debug)]
+pub struct RTCRayHit16 {
+    pub ray: RTCRay16,
+    pub hit: RTCHit16,
+}
+#[test]
+fn bindgen_test_layout_RTCRayHit16() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCRayHit16>(),
+        1344usize,
+        concat!("Size of: ", stringify!(RTCRayHit16))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCRayHit16>(),
+        64usize,
+        concat!("Alignment of ", stringify!(RTCRayHit16))
+    );
+    fn test_field_ray() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRayHit16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ray) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRayHit16),
+                "::",
+                stringify!(ray)
+            )
+        );
+    }
+    test_field_ray();
+    fn test_field_hit() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCRayHit16>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).hit) as usize - ptr as usize
+            },
+            768usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCRayHit16),
+                "::",
+                stringify!(hit)
+            )
+        );
+    }
+    test_field_hit();
+}
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCRayHitN {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug)]
+pub struct RTCQuaternionDecomposition {
+    pub scale_x: f32,
+    pub scale_y: f32,
+    pub scale_z: f32,
+    pub skew_xy: f32,
+    pub skew_xz: f32,
+    pub skew_yz: f32,
+    pub shift_x: f32,
+    pub shift_y: f32,
+    pub shift_z: f32,
+    pub quaternion_r: f32,
+    pub quaternion_i: f32,
+    pub quaternion_j: f32,
+    pub quaternion_k: f32,
+    pub translation_x: f32,
+    pub translation_y: f32,
+    pub translation_z: f32,
+}
+#[test]
+fn bindgen_test_layout_RTCQuaternionDecomposition() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCQuaternionDecomposition>(),
+        64usize,
+        concat!("Size of: ", stringify!(RTCQuaternionDecomposition))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCQuaternionDecomposition>(),
+        16usize,
+        concat!("Alignment of ", stringify!(RTCQuaternionDecomposition))
+    );
+    fn test_field_scale_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCQuaternionDecomposition>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).scale_x) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCQuaternionDecomposition),
+                "::",
+                stringify!(scale_x)
+            )
+        );
+    }
+    test_field_scale_x();
+    fn test_field_scale_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCQuaternionDecomposition>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).scale_y) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCQuaternionDecomposition),
+                "::",
+                stringify!(scale_y)
+            )
+        );
+    }
+    test_field_scale_y();
+    fn test_field_scale_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCQuaternionDecomposition>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).scale_z) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCQuaternionDecomposition),
+                "::",
+                stringify!(scale_z)
+            )
+        );
+    }
+    test_field_scale_z();
+    fn test_field_skew_xy() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCQuaternionDecomposition>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).skew_xy) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCQuaternionDecomposition),
+                "::",
+                stringify!(skew_xy)
+            )
+        );
+    }
+    test_field_skew_xy();
+    fn test_field_skew_xz() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCQuaternionDecomposition>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).skew_xz) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCQuaternionDecomposition),
+                "::",
+                stringify!(skew_xz)
+            )
+        );
+    }
+    test_field_skew_xz();
+    fn test_field_skew_yz() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCQuaternionDecomposition>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).skew_yz) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCQuaternionDecomposition),
+                "::",
+                stringify!(skew_yz)
+            )
+        );
+    }
+    test_field_skew_yz();
+    fn test_field_shift_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCQuaternionDecomposition>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).shift_x) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCQuaternionDecomposition),
+                "::",
+                stringify!(shift_x)
+            )
+        );
+    }
+    test_field_shift_x();
+    fn test_field_shift_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCQuaternionDecomposition>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).shift_y) as usize - ptr as usize
+            },
+            28usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCQuaternionDecomposition),
+                "::",
+                stringify!(shift_y)
+            )
+        );
+    }
+    test_field_shift_y();
+    fn test_field_shift_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCQuaternionDecomposition>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).shift_z) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCQuaternionDecomposition),
+                "::",
+                stringify!(shift_z)
+            )
+        );
+    }
+    test_field_shift_z();
+    fn test_field_quaternion_r() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCQuaternionDecomposition>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).quaternion_r) as usize - ptr as usize
+            },
+            36usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCQuaternionDecomposition),
+                "::",
+                stringify!(quaternion_r)
+            )
+        );
+    }
+    test_field_quaternion_r();
+    fn test_field_quaternion_i() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCQuaternionDecomposition>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).quaternion_i) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCQuaternionDecomposition),
+                "::",
+                stringify!(quaternion_i)
+            )
+        );
+    }
+    test_field_quaternion_i();
+    fn test_field_quaternion_j() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCQuaternionDecomposition>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).quaternion_j) as usize - ptr as usize
+            },
+            44usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCQuaternionDecomposition),
+                "::",
+                stringify!(quaternion_j)
+            )
+        );
+    }
+    test_field_quaternion_j();
+    fn test_field_quaternion_k() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCQuaternionDecomposition>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).quaternion_k) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCQuaternionDecomposition),
+                "::",
+                stringify!(quaternion_k)
+            )
+        );
+    }
+    test_field_quaternion_k();
+    fn test_field_translation_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCQuaternionDecomposition>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).translation_x) as usize - ptr as usize
+            },
+            52usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCQuaternionDecomposition),
+                "::",
+                stringify!(translation_x)
+            )
+        );
+    }
+    test_field_translation_x();
+    fn test_field_translation_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCQuaternionDecomposition>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).translation_y) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCQuaternionDecomposition),
+                "::",
+                stringify!(translation_y)
+            )
+        );
+    }
+    test_field_translation_y();
+    fn test_field_translation_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCQuaternionDecomposition>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).translation_z) as usize - ptr as usize
+            },
+            60usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCQuaternionDecomposition),
+                "::",
+                stringify!(translation_z)
+            )
+        );
+    }
+    test_field_translation_z();
+}
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCSceneTy {
+    _unused: [u8; 0],
+}
+pub type RTCScene = *mut RTCSceneTy;
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCGeometryTy {
+    _unused: [u8; 0],
+}
+pub type RTCGeometry = *mut RTCGeometryTy;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_TRIANGLE: RTCGeometryType = 0;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_QUAD: RTCGeometryType = 1;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_GRID: RTCGeometryType = 2;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_SUBDIVISION: RTCGeometryType = 8;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_CONE_LINEAR_CURVE: RTCGeometryType = 15;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_ROUND_LINEAR_CURVE: RTCGeometryType = 16;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_FLAT_LINEAR_CURVE: RTCGeometryType = 17;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_ROUND_BEZIER_CURVE: RTCGeometryType = 24;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_FLAT_BEZIER_CURVE: RTCGeometryType = 25;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_NORMAL_ORIENTED_BEZIER_CURVE: RTCGeometryType = 26;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_ROUND_BSPLINE_CURVE: RTCGeometryType = 32;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_FLAT_BSPLINE_CURVE: RTCGeometryType = 33;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_NORMAL_ORIENTED_BSPLINE_CURVE: RTCGeometryType = 34;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_ROUND_HERMITE_CURVE: RTCGeometryType = 40;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_FLAT_HERMITE_CURVE: RTCGeometryType = 41;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_NORMAL_ORIENTED_HERMITE_CURVE: RTCGeometryType = 42;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_SPHERE_POINT: RTCGeometryType = 50;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_DISC_POINT: RTCGeometryType = 51;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_ORIENTED_DISC_POINT: RTCGeometryType = 52;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_ROUND_CATMULL_ROM_CURVE: RTCGeometryType = 58;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_FLAT_CATMULL_ROM_CURVE: RTCGeometryType = 59;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_NORMAL_ORIENTED_CATMULL_ROM_CURVE: RTCGeometryType = 60;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_USER: RTCGeometryType = 120;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_INSTANCE: RTCGeometryType = 121;
+pub const RTCGeometryType_RTC_GEOMETRY_TYPE_INSTANCE_ARRAY: RTCGeometryType = 122;
+pub type RTCGeometryType = ::std::os::raw::c_uint;
+pub const RTCSubdivisionMode_RTC_SUBDIVISION_MODE_NO_BOUNDARY: RTCSubdivisionMode = 0;
+pub const RTCSubdivisionMode_RTC_SUBDIVISION_MODE_SMOOTH_BOUNDARY: RTCSubdivisionMode = 1;
+pub const RTCSubdivisionMode_RTC_SUBDIVISION_MODE_PIN_CORNERS: RTCSubdivisionMode = 2;
+pub const RTCSubdivisionMode_RTC_SUBDIVISION_MODE_PIN_BOUNDARY: RTCSubdivisionMode = 3;
+pub const RTCSubdivisionMode_RTC_SUBDIVISION_MODE_PIN_ALL: RTCSubdivisionMode = 4;
+pub type RTCSubdivisionMode = ::std::os::raw::c_uint;
+pub const RTCCurveFlags_RTC_CURVE_FLAG_NEIGHBOR_LEFT: RTCCurveFlags = 1;
+pub const RTCCurveFlags_RTC_CURVE_FLAG_NEIGHBOR_RIGHT: RTCCurveFlags = 2;
+pub type RTCCurveFlags = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCBoundsFunctionArguments {
+    pub geometryUserPtr: *mut ::std::os::raw::c_void,
+    pub primID: ::std::os::raw::c_uint,
+    pub timeStep: ::std::os::raw::c_uint,
+    pub bounds_o: *mut RTCBounds,
+}
+#[test]
+fn bindgen_test_layout_RTCBoundsFunctionArguments() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCBoundsFunctionArguments>(),
+        24usize,
+        concat!("Size of: ", stringify!(RTCBoundsFunctionArguments))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCBoundsFunctionArguments>(),
+        8usize,
+        concat!("Alignment of ", stringify!(RTCBoundsFunctionArguments))
+    );
+    fn test_field_geometryUserPtr() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBoundsFunctionArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).geometryUserPtr) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBoundsFunctionArguments),
+                "::",
+                stringify!(geometryUserPtr)
+            )
+        );
+    }
+    test_field_geometryUserPtr();
+    fn test_field_primID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBoundsFunctionArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).primID) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBoundsFunctionArguments),
+                "::",
+                stringify!(primID)
+            )
+        );
+    }
+    test_field_primID();
+    fn test_field_timeStep() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBoundsFunctionArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).timeStep) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBoundsFunctionArguments),
+                "::",
+                stringify!(timeStep)
+            )
+        );
+    }
+    test_field_timeStep();
+    fn test_field_bounds_o() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBoundsFunctionArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).bounds_o) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBoundsFunctionArguments),
+                "::",
+                stringify!(bounds_o)
+            )
+        );
+    }
+    test_field_bounds_o();
+}
+pub type RTCBoundsFunction =
+    ::std::option::Option<unsafe extern "C" fn(args: *const RTCBoundsFunctionArguments)>;
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCIntersectFunctionNArguments {
+    pub valid: *mut ::std::os::raw::c_int,
+    pub geometryUserPtr: *mut ::std::os::raw::c_void,
+    pub primID: ::std::os::raw::c_uint,
+    pub context: *mut RTCRayQueryContext,
+    pub rayhit: *mut RTCRayHitN,
+    pub N: ::std::os::raw::c_uint,
+    pub geomID: ::std::os::raw::c_uint,
+    pub args: *mut RTCIntersectArguments,
+}
+#[test]
+fn bindgen_test_layout_RTCIntersectFunctionNArguments() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCIntersectFunctionNArguments>(),
+        56usize,
+        concat!("Size of: ", stringify!(RTCIntersectFunctionNArguments))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCIntersectFunctionNArguments>(),
+        8usize,
+        concat!("Alignment of ", stringify!(RTCIntersectFunctionNArguments))
+    );
+    fn test_field_valid() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCIntersectFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).valid) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCIntersectFunctionNArguments),
+                "::",
+                stringify!(valid)
+            )
+        );
+    }
+    test_field_valid();
+    fn test_field_geometryUserPtr() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCIntersectFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).geometryUserPtr) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCIntersectFunctionNArguments),
+                "::",
+                stringify!(geometryUserPtr)
+            )
+        );
+    }
+    test_field_geometryUserPtr();
+    fn test_field_primID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCIntersectFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).primID) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCIntersectFunctionNArguments),
+                "::",
+                stringify!(primID)
+            )
+        );
+    }
+    test_field_primID();
+    fn test_field_context() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCIntersectFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).context) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCIntersectFunctionNArguments),
+                "::",
+                stringify!(context)
+            )
+        );
+    }
+    test_field_context();
+    fn test_field_rayhit() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCIntersectFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).rayhit) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCIntersectFunctionNArguments),
+                "::",
+                stringify!(rayhit)
+            )
+        );
+    }
+    test_field_rayhit();
+    fn test_field_N() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCIntersectFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).N) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCIntersectFunctionNArguments),
+                "::",
+                stringify!(N)
+            )
+        );
+    }
+    test_field_N();
+    fn test_field_geomID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCIntersectFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).geomID) as usize - ptr as usize
+            },
+            44usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCIntersectFunctionNArguments),
+                "::",
+                stringify!(geomID)
+            )
+        );
+    }
+    test_field_geomID();
+    fn test_field_args() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCIntersectFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).args) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCIntersectFunctionNArguments),
+                "::",
+                stringify!(args)
+            )
+        );
+    }
+    test_field_args();
+}
+pub type RTCIntersectFunctionN =
+    ::std::option::Option<unsafe extern "C" fn(args: *const RTCIntersectFunctionNArguments)>;
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCOccludedFunctionNArguments {
+    pub valid: *mut ::std::os::raw::c_int,
+    pub geometryUserPtr: *mut ::std::os::raw::c_void,
+    pub primID: ::std::os::raw::c_uint,
+    pub context: *mut RTCRayQueryContext,
+    pub ray: *mut RTCRayN,
+    pub N: ::std::os::raw::c_uint,
+    pub geomID: ::std::os::raw::c_uint,
+    pub args: *mut RTCOccludedArguments,
+}
+#[test]
+fn bindgen_test_layout_RTCOccludedFunctionNArguments() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCOccludedFunctionNArguments>(),
+        56usize,
+        concat!("Size of: ", stringify!(RTCOccludedFunctionNArguments))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCOccludedFunctionNArguments>(),
+        8usize,
+        concat!("Alignment of ", stringify!(RTCOccludedFunctionNArguments))
+    );
+    fn test_field_valid() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCOccludedFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).valid) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCOccludedFunctionNArguments),
+                "::",
+                stringify!(valid)
+            )
+        );
+    }
+    test_field_valid();
+    fn test_field_geometryUserPtr() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCOccludedFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).geometryUserPtr) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCOccludedFunctionNArguments),
+                "::",
+                stringify!(geometryUserPtr)
+            )
+        );
+    }
+    test_field_geometryUserPtr();
+    fn test_field_primID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCOccludedFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).primID) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCOccludedFunctionNArguments),
+                "::",
+                stringify!(primID)
+            )
+        );
+    }
+    test_field_primID();
+    fn test_field_context() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCOccludedFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).context) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCOccludedFunctionNArguments),
+                "::",
+                stringify!(context)
+            )
+        );
+    }
+    test_field_context();
+    fn test_field_ray() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCOccludedFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ray) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCOccludedFunctionNArguments),
+                "::",
+                stringify!(ray)
+            )
+        );
+    }
+    test_field_ray();
+    fn test_field_N() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCOccludedFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).N) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCOccludedFunctionNArguments),
+                "::",
+                stringify!(N)
+            )
+        );
+    }
+    test_field_N();
+    fn test_field_geomID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCOccludedFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).geomID) as usize - ptr as usize
+            },
+            44usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCOccludedFunctionNArguments),
+                "::",
+                stringify!(geomID)
+            )
+        );
+    }
+    test_field_geomID();
+    fn test_field_args() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCOccludedFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).args) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCOccludedFunctionNArguments),
+                "::",
+                stringify!(args)
+            )
+        );
+    }
+    test_field_args();
+}
+pub type RTCOccludedFunctionN =
+    ::std::option::Option<unsafe extern "C" fn(args: *const RTCOccludedFunctionNArguments)>;
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCDisplacementFunctionNArguments {
+    pub geometryUserPtr: *mut ::std::os::raw::c_void,
+    pub geometry: RTCGeometry,
+    pub primID: ::std::os::raw::c_uint,
+    pub timeStep: ::std::os::raw::c_uint,
+    pub u: *const f32,
+    pub v: *const f32,
+    pub Ng_x: *const f32,
+    pub Ng_y: *const f32,
+    pub Ng_z: *const f32,
+    pub P_x: *mut f32,
+    pub P_y: *mut f32,
+    pub P_z: *mut f32,
+    pub N: ::std::os::raw::c_uint,
+}
+#[test]
+fn bindgen_test_layout_RTCDisplacementFunctionNArguments() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCDisplacementFunctionNArguments>(),
+        96usize,
+        concat!("Size of: ", stringify!(RTCDisplacementFunctionNArguments))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCDisplacementFunctionNArguments>(),
+        8usize,
+        concat!(
+            "Alignment of ",
+            stringify!(RTCDisplacementFunctionNArguments)
+        )
+    );
+    fn test_field_geometryUserPtr() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCDisplacementFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).geometryUserPtr) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCDisplacementFunctionNArguments),
+                "::",
+                stringify!(geometryUserPtr)
+            )
+        );
+    }
+    test_field_geometryUserPtr();
+    fn test_field_geometry() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCDisplacementFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).geometry) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCDisplacementFunctionNArguments),
+                "::",
+                stringify!(geometry)
+            )
+        );
+    }
+    test_field_geometry();
+    fn test_field_primID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCDisplacementFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).primID) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCDisplacementFunctionNArguments),
+                "::",
+                stringify!(primID)
+            )
+        );
+    }
+    test_field_primID();
+    fn test_field_timeStep() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCDisplacementFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).timeStep) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCDisplacementFunctionNArguments),
+                "::",
+                stringify!(timeStep)
+            )
+        );
+    }
+    test_field_timeStep();
+    fn test_field_u() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCDisplacementFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).u) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCDisplacementFunctionNArguments),
+                "::",
+                stringify!(u)
+            )
+        );
+    }
+    test_field_u();
+    fn test_field_v() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCDisplacementFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).v) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCDisplacementFunctionNArguments),
+                "::",
+                stringify!(v)
+            )
+        );
+    }
+    test_field_v();
+    fn test_field_Ng_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCDisplacementFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).Ng_x) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCDisplacementFunctionNArguments),
+                "::",
+                stringify!(Ng_x)
+            )
+        );
+    }
+    test_field_Ng_x();
+    fn test_field_Ng_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCDisplacementFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).Ng_y) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCDisplacementFunctionNArguments),
+                "::",
+                stringify!(Ng_y)
+            )
+        );
+    }
+    test_field_Ng_y();
+    fn test_field_Ng_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCDisplacementFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).Ng_z) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCDisplacementFunctionNArguments),
+                "::",
+                stringify!(Ng_z)
+            )
+        );
+    }
+    test_field_Ng_z();
+    fn test_field_P_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCDisplacementFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).P_x) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCDisplacementFunctionNArguments),
+                "::",
+                stringify!(P_x)
+            )
+        );
+    }
+    test_field_P_x();
+    fn test_field_P_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCDisplacementFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).P_y) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCDisplacementFunctionNArguments),
+                "::",
+                stringify!(P_y)
+            )
+        );
+    }
+    test_field_P_y();
+    fn test_field_P_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCDisplacementFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).P_z) as usize - ptr as usize
+            },
+            80usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCDisplacementFunctionNArguments),
+                "::",
+                stringify!(P_z)
+            )
+        );
+    }
+    test_field_P_z();
+    fn test_field_N() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCDisplacementFunctionNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).N) as usize - ptr as usize
+            },
+            88usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCDisplacementFunctionNArguments),
+                "::",
+                stringify!(N)
+            )
+        );
+    }
+    test_field_N();
+}
+pub type RTCDisplacementFunctionN =
+    ::std::option::Option<unsafe extern "C" fn(args: *const RTCDisplacementFunctionNArguments)>;
+extern "C" {
+    pub fn rtcNewGeometry(device: RTCDevice, type_: RTCGeometryType) -> RTCGeometry;
+}
+extern "C" {
+    pub fn rtcRetainGeometry(geometry: RTCGeometry);
+}
+extern "C" {
+    pub fn rtcReleaseGeometry(geometry: RTCGeometry);
+}
+extern "C" {
+    pub fn rtcCommitGeometry(geometry: RTCGeometry);
+}
+extern "C" {
+    pub fn rtcEnableGeometry(geometry: RTCGeometry);
+}
+extern "C" {
+    pub fn rtcDisableGeometry(geometry: RTCGeometry);
+}
+extern "C" {
+    pub fn rtcSetGeometryTimeStepCount(
+        geometry: RTCGeometry,
+        timeStepCount: ::std::os::raw::c_uint,
+    );
+}
+extern "C" {
+    pub fn rtcSetGeometryTimeRange(geometry: RTCGeometry, startTime: f32, endTime: f32);
+}
+extern "C" {
+    pub fn rtcSetGeometryVertexAttributeCount(
+        geometry: RTCGeometry,
+        vertexAttributeCount: ::std::os::raw::c_uint,
+    );
+}
+extern "C" {
+    pub fn rtcSetGeometryMask(geometry: RTCGeometry, mask: ::std::os::raw::c_uint);
+}
+extern "C" {
+    pub fn rtcSetGeometryBuildQuality(geometry: RTCGeometry, quality: RTCBuildQuality);
+}
+extern "C" {
+    pub fn rtcSetGeometryMaxRadiusScale(geometry: RTCGeometry, maxRadiusScale: f32);
+}
+extern "C" {
+    pub fn rtcSetGeometryBuffer(
+        geometry: RTCGeometry,
+        type_: RTCBufferType,
+        slot: ::std::os::raw::c_uint,
+        format: RTCFormat,
+        buffer: RTCBuffer,
+        byteOffset: usize,
+        byteStride: usize,
+        itemCount: usize,
+    );
+}
+extern "C" {
+    pub fn rtcSetSharedGeometryBuffer(
+        geometry: RTCGeometry,
+        type_: RTCBufferType,
+        slot: ::std::os::raw::c_uint,
+        format: RTCFormat,
+        ptr: *const ::std::os::raw::c_void,
+        byteOffset: usize,
+        byteStride: usize,
+        itemCount: usize,
+    );
+}
+extern "C" {
+    pub fn rtcSetNewGeometryBuffer(
+        geometry: RTCGeometry,
+        type_: RTCBufferType,
+        slot: ::std::os::raw::c_uint,
+        format: RTCFormat,
+        byteStride: usize,
+        itemCount: usize,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn rtcGetGeometryBufferData(
+        geometry: RTCGeometry,
+        type_: RTCBufferType,
+        slot: ::std::os::raw::c_uint,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn rtcUpdateGeometryBuffer(
+        geometry: RTCGeometry,
+        type_: RTCBufferType,
+        slot: ::std::os::raw::c_uint,
+    );
+}
+extern "C" {
+    pub fn rtcSetGeometryIntersectFilterFunction(geometry: RTCGeometry, filter: RTCFilterFunctionN);
+}
+extern "C" {
+    pub fn rtcSetGeometryOccludedFilterFunction(geometry: RTCGeometry, filter: RTCFilterFunctionN);
+}
+extern "C" {
+    pub fn rtcSetGeometryEnableFilterFunctionFromArguments(geometry: RTCGeometry, enable: bool);
+}
+extern "C" {
+    pub fn rtcSetGeometryUserData(geometry: RTCGeometry, ptr: *mut ::std::os::raw::c_void);
+}
+extern "C" {
+    pub fn rtcGetGeometryUserData(geometry: RTCGeometry) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn rtcSetGeometryPointQueryFunction(
+        geometry: RTCGeometry,
+        pointQuery: RTCPointQueryFunction,
+    );
+}
+extern "C" {
+    pub fn rtcSetGeometryUserPrimitiveCount(
+        geometry: RTCGeometry,
+        userPrimitiveCount: ::std::os::raw::c_uint,
+    );
+}
+extern "C" {
+    pub fn rtcSetGeometryBoundsFunction(
+        geometry: RTCGeometry,
+        bounds: RTCBoundsFunction,
+        userPtr: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn rtcSetGeometryIntersectFunction(geometry: RTCGeometry, intersect: RTCIntersectFunctionN);
+}
+extern "C" {
+    pub fn rtcSetGeometryOccludedFunction(geometry: RTCGeometry, occluded: RTCOccludedFunctionN);
+}
+extern "C" {
+    pub fn rtcFilterIntersection(
+        args: *const RTCIntersectFunctionNArguments,
+        filterArgs: *const RTCFilterFunctionNArguments,
+    );
+}
+extern "C" {
+    pub fn rtcFilterOcclusion(
+        args: *const RTCOccludedFunctionNArguments,
+        filterArgs: *const RTCFilterFunctionNArguments,
+    );
+}
+extern "C" {
+    pub fn rtcSetGeometryInstancedScene(geometry: RTCGeometry, scene: RTCScene);
+}
+extern "C" {
+    pub fn rtcSetGeometryTransform(
+        geometry: RTCGeometry,
+        timeStep: ::std::os::raw::c_uint,
+        format: RTCFormat,
+        xfm: *const ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn rtcSetGeometryTransformQuaternion(
+        geometry: RTCGeometry,
+        timeStep: ::std::os::raw::c_uint,
+        qd: *const RTCQuaternionDecomposition,
+    );
+}
+extern "C" {
+    pub fn rtcGetGeometryTransform(
+        geometry: RTCGeometry,
+        time: f32,
+        format: RTCFormat,
+        xfm: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn rtcSetGeometryTessellationRate(geometry: RTCGeometry, tessellationRate: f32);
+}
+extern "C" {
+    pub fn rtcSetGeometryTopologyCount(
+        geometry: RTCGeometry,
+        topologyCount: ::std::os::raw::c_uint,
+    );
+}
+extern "C" {
+    pub fn rtcSetGeometrySubdivisionMode(
+        geometry: RTCGeometry,
+        topologyID: ::std::os::raw::c_uint,
+        mode: RTCSubdivisionMode,
+    );
+}
+extern "C" {
+    pub fn rtcSetGeometryVertexAttributeTopology(
+        geometry: RTCGeometry,
+        vertexAttributeID: ::std::os::raw::c_uint,
+        topologyID: ::std::os::raw::c_uint,
+    );
+}
+extern "C" {
+    pub fn rtcSetGeometryDisplacementFunction(
+        geometry: RTCGeometry,
+        displacement: RTCDisplacementFunctionN,
+    );
+}
+extern "C" {
+    pub fn rtcGetGeometryFirstHalfEdge(
+        geometry: RTCGeometry,
+        faceID: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn rtcGetGeometryFace(
+        geometry: RTCGeometry,
+        edgeID: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn rtcGetGeometryNextHalfEdge(
+        geometry: RTCGeometry,
+        edgeID: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn rtcGetGeometryPreviousHalfEdge(
+        geometry: RTCGeometry,
+        edgeID: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn rtcGetGeometryOppositeHalfEdge(
+        geometry: RTCGeometry,
+        topologyID: ::std::os::raw::c_uint,
+        edgeID: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCInterpolateArguments {
+    pub geometry: RTCGeometry,
+    pub primID: ::std::os::raw::c_uint,
+    pub u: f32,
+    pub v: f32,
+    pub bufferType: RTCBufferType,
+    pub bufferSlot: ::std::os::raw::c_uint,
+    pub P: *mut f32,
+    pub dPdu: *mut f32,
+    pub dPdv: *mut f32,
+    pub ddPdudu: *mut f32,
+    pub ddPdvdv: *mut f32,
+    pub ddPdudv: *mut f32,
+    pub valueCount: ::std::os::raw::c_uint,
+}
+#[test]
+fn bindgen_test_layout_RTCInterpolateArguments() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCInterpolateArguments>(),
+        88usize,
+        concat!("Size of: ", stringify!(RTCInterpolateArguments))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCInterpolateArguments>(),
+        8usize,
+        concat!("Alignment of ", stringify!(RTCInterpolateArguments))
+    );
+    fn test_field_geometry() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).geometry) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateArguments),
+                "::",
+                stringify!(geometry)
+            )
+        );
+    }
+    test_field_geometry();
+    fn test_field_primID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).primID) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateArguments),
+                "::",
+                stringify!(primID)
+            )
+        );
+    }
+    test_field_primID();
+    fn test_field_u() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).u) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateArguments),
+                "::",
+                stringify!(u)
+            )
+        );
+    }
+    test_field_u();
+    fn test_field_v() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).v) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateArguments),
+                "::",
+                stringify!(v)
+            )
+        );
+    }
+    test_field_v();
+    fn test_field_bufferType() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).bufferType) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateArguments),
+                "::",
+                stringify!(bufferType)
+            )
+        );
+    }
+    test_field_bufferType();
+    fn test_field_bufferSlot() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).bufferSlot) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateArguments),
+                "::",
+                stringify!(bufferSlot)
+            )
+        );
+    }
+    test_field_bufferSlot();
+    fn test_field_P() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).P) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateArguments),
+                "::",
+                stringify!(P)
+            )
+        );
+    }
+    test_field_P();
+    fn test_field_dPdu() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).dPdu) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateArguments),
+                "::",
+                stringify!(dPdu)
+            )
+        );
+    }
+    test_field_dPdu();
+    fn test_field_dPdv() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).dPdv) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateArguments),
+                "::",
+                stringify!(dPdv)
+            )
+        );
+    }
+    test_field_dPdv();
+    fn test_field_ddPdudu() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ddPdudu) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateArguments),
+                "::",
+                stringify!(ddPdudu)
+            )
+        );
+    }
+    test_field_ddPdudu();
+    fn test_field_ddPdvdv() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ddPdvdv) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateArguments),
+                "::",
+                stringify!(ddPdvdv)
+            )
+        );
+    }
+    test_field_ddPdvdv();
+    fn test_field_ddPdudv() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ddPdudv) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateArguments),
+                "::",
+                stringify!(ddPdudv)
+            )
+        );
+    }
+    test_field_ddPdudv();
+    fn test_field_valueCount() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).valueCount) as usize - ptr as usize
+            },
+            80usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateArguments),
+                "::",
+                stringify!(valueCount)
+            )
+        );
+    }
+    test_field_valueCount();
+}
+extern "C" {
+    pub fn rtcInterpolate(args: *const RTCInterpolateArguments);
+}
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCInterpolateNArguments {
+    pub geometry: RTCGeometry,
+    pub valid: *const ::std::os::raw::c_void,
+    pub primIDs: *const ::std::os::raw::c_uint,
+    pub u: *const f32,
+    pub v: *const f32,
+    pub N: ::std::os::raw::c_uint,
+    pub bufferType: RTCBufferType,
+    pub bufferSlot: ::std::os::raw::c_uint,
+    pub P: *mut f32,
+    pub dPdu: *mut f32,
+    pub dPdv: *mut f32,
+    pub ddPdudu: *mut f32,
+    pub ddPdvdv: *mut f32,
+    pub ddPdudv: *mut f32,
+    pub valueCount: ::std::os::raw::c_uint,
+}
+#[test]
+fn bindgen_test_layout_RTCInterpolateNArguments() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCInterpolateNArguments>(),
+        112usize,
+        concat!("Size of: ", stringify!(RTCInterpolateNArguments))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCInterpolateNArguments>(),
+        8usize,
+        concat!("Alignment of ", stringify!(RTCInterpolateNArguments))
+    );
+    fn test_field_geometry() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).geometry) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateNArguments),
+                "::",
+                stringify!(geometry)
+            )
+        );
+    }
+    test_field_geometry();
+    fn test_field_valid() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).valid) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateNArguments),
+                "::",
+                stringify!(valid)
+            )
+        );
+    }
+    test_field_valid();
+    fn test_field_primIDs() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).primIDs) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateNArguments),
+                "::",
+                stringify!(primIDs)
+            )
+        );
+    }
+    test_field_primIDs();
+    fn test_field_u() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).u) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateNArguments),
+                "::",
+                stringify!(u)
+            )
+        );
+    }
+    test_field_u();
+    fn test_field_v() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).v) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateNArguments),
+                "::",
+                stringify!(v)
+            )
+        );
+    }
+    test_field_v();
+    fn test_field_N() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).N) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateNArguments),
+                "::",
+                stringify!(N)
+            )
+        );
+    }
+    test_field_N();
+    fn test_field_bufferType() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).bufferType) as usize - ptr as usize
+            },
+            44usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateNArguments),
+                "::",
+                stringify!(bufferType)
+            )
+        );
+    }
+    test_field_bufferType();
+    fn test_field_bufferSlot() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).bufferSlot) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateNArguments),
+                "::",
+                stringify!(bufferSlot)
+            )
+        );
+    }
+    test_field_bufferSlot();
+    fn test_field_P() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).P) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateNArguments),
+                "::",
+                stringify!(P)
+            )
+        );
+    }
+    test_field_P();
+    fn test_field_dPdu() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).dPdu) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateNArguments),
+                "::",
+                stringify!(dPdu)
+            )
+        );
+    }
+    test_field_dPdu();
+    fn test_field_dPdv() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).dPdv) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateNArguments),
+                "::",
+                stringify!(dPdv)
+            )
+        );
+    }
+    test_field_dPdv();
+    fn test_field_ddPdudu() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ddPdudu) as usize - ptr as usize
+            },
+            80usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateNArguments),
+                "::",
+                stringify!(ddPdudu)
+            )
+        );
+    }
+    test_field_ddPdudu();
+    fn test_field_ddPdvdv() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ddPdvdv) as usize - ptr as usize
+            },
+            88usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateNArguments),
+                "::",
+                stringify!(ddPdvdv)
+            )
+        );
+    }
+    test_field_ddPdvdv();
+    fn test_field_ddPdudv() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).ddPdudv) as usize - ptr as usize
+            },
+            96usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateNArguments),
+                "::",
+                stringify!(ddPdudv)
+            )
+        );
+    }
+    test_field_ddPdudv();
+    fn test_field_valueCount() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCInterpolateNArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).valueCount) as usize - ptr as usize
+            },
+            104usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCInterpolateNArguments),
+                "::",
+                stringify!(valueCount)
+            )
+        );
+    }
+    test_field_valueCount();
+}
+extern "C" {
+    pub fn rtcInterpolateN(args: *const RTCInterpolateNArguments);
+}
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCGrid {
+    pub startVertexID: ::std::os::raw::c_uint,
+    pub stride: ::std::os::raw::c_uint,
+    pub width: ::std::os::raw::c_ushort,
+    pub height: ::std::os::raw::c_ushort,
+}
+#[test]
+fn bindgen_test_layout_RTCGrid() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCGrid>(),
+        12usize,
+        concat!("Size of: ", stringify!(RTCGrid))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCGrid>(),
+        4usize,
+        concat!("Alignment of ", stringify!(RTCGrid))
+    );
+    fn test_field_startVertexID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCGrid>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).startVertexID) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCGrid),
+                "::",
+                stringify!(startVertexID)
+            )
+        );
+    }
+    test_field_startVertexID();
+    fn test_field_stride() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCGrid>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).stride) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCGrid),
+                "::",
+                stringify!(stride)
+            )
+        );
+    }
+    test_field_stride();
+    fn test_field_width() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCGrid>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).width) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCGrid),
+                "::",
+                stringify!(width)
+            )
+        );
+    }
+    test_field_width();
+    fn test_field_height() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCGrid>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).height) as usize - ptr as usize
+            },
+            10usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCGrid),
+                "::",
+                stringify!(height)
+            )
+        );
+    }
+    test_field_height();
+}
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCIntersectArguments {
+    pub flags: RTCRayQueryFlags,
+    pub feature_mask: RTCFeatureFlags,
+    pub context: *mut RTCRayQueryContext,
+    pub filter: RTCFilterFunctionN,
+    pub intersect: RTCIntersectFunctionN,
+}
+#[test]
+fn bindgen_test_layout_RTCIntersectArguments() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCIntersectArguments>(),
+        32usize,
+        concat!("Size of: ", stringify!(RTCIntersectArguments))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCIntersectArguments>(),
+        8usize,
+        concat!("Alignment of ", stringify!(RTCIntersectArguments))
+    );
+    fn test_field_flags() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCIntersectArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCIntersectArguments),
+                "::",
+                stringify!(flags)
+            )
+        );
+    }
+    test_field_flags();
+    fn test_field_feature_mask() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCIntersectArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).feature_mask) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCIntersectArguments),
+                "::",
+                stringify!(feature_mask)
+            )
+        );
+    }
+    test_field_feature_mask();
+    fn test_field_context() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCIntersectArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).context) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCIntersectArguments),
+                "::",
+                stringify!(context)
+            )
+        );
+    }
+    test_field_context();
+    fn test_field_filter() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCIntersectArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).filter) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCIntersectArguments),
+                "::",
+                stringify!(filter)
+            )
+        );
+    }
+    test_field_filter();
+    fn test_field_intersect() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCIntersectArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).intersect) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCIntersectArguments),
+                "::",
+                stringify!(intersect)
+            )
+        );
+    }
+    test_field_intersect();
+}
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCOccludedArguments {
+    pub flags: RTCRayQueryFlags,
+    pub feature_mask: RTCFeatureFlags,
+    pub context: *mut RTCRayQueryContext,
+    pub filter: RTCFilterFunctionN,
+    pub occluded: RTCOccludedFunctionN,
+}
+#[test]
+fn bindgen_test_layout_RTCOccludedArguments() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCOccludedArguments>(),
+        32usize,
+        concat!("Size of: ", stringify!(RTCOccludedArguments))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCOccludedArguments>(),
+        8usize,
+        concat!("Alignment of ", stringify!(RTCOccludedArguments))
+    );
+    fn test_field_flags() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCOccludedArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCOccludedArguments),
+                "::",
+                stringify!(flags)
+            )
+        );
+    }
+    test_field_flags();
+    fn test_field_feature_mask() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCOccludedArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).feature_mask) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCOccludedArguments),
+                "::",
+                stringify!(feature_mask)
+            )
+        );
+    }
+    test_field_feature_mask();
+    fn test_field_context() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCOccludedArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).context) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCOccludedArguments),
+                "::",
+                stringify!(context)
+            )
+        );
+    }
+    test_field_context();
+    fn test_field_filter() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCOccludedArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).filter) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCOccludedArguments),
+                "::",
+                stringify!(filter)
+            )
+        );
+    }
+    test_field_filter();
+    fn test_field_occluded() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCOccludedArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).occluded) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCOccludedArguments),
+                "::",
+                stringify!(occluded)
+            )
+        );
+    }
+    test_field_occluded();
+}
+pub const RTCSceneFlags_RTC_SCENE_FLAG_NONE: RTCSceneFlags = 0;
+pub const RTCSceneFlags_RTC_SCENE_FLAG_DYNAMIC: RTCSceneFlags = 1;
+pub const RTCSceneFlags_RTC_SCENE_FLAG_COMPACT: RTCSceneFlags = 2;
+pub const RTCSceneFlags_RTC_SCENE_FLAG_ROBUST: RTCSceneFlags = 4;
+pub const RTCSceneFlags_RTC_SCENE_FLAG_FILTER_FUNCTION_IN_ARGUMENTS: RTCSceneFlags = 8;
+pub const RTCSceneFlags_RTC_SCENE_FLAG_PREFETCH_USM_SHARED_ON_GPU: RTCSceneFlags = 16;
+pub type RTCSceneFlags = ::std::os::raw::c_uint;
+extern "C" {
+    pub fn rtcNewScene(device: RTCDevice) -> RTCScene;
+}
+extern "C" {
+    pub fn rtcGetSceneDevice(hscene: RTCScene) -> RTCDevice;
+}
+extern "C" {
+    pub fn rtcRetainScene(scene: RTCScene);
+}
+extern "C" {
+    pub fn rtcReleaseScene(scene: RTCScene);
+}
+extern "C" {
+    pub fn rtcAttachGeometry(scene: RTCScene, geometry: RTCGeometry) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn rtcAttachGeometryByID(
+        scene: RTCScene,
+        geometry: RTCGeometry,
+        geomID: ::std::os::raw::c_uint,
+    );
+}
+extern "C" {
+    pub fn rtcDetachGeometry(scene: RTCScene, geomID: ::std::os::raw::c_uint);
+}
+extern "C" {
+    pub fn rtcGetGeometry(scene: RTCScene, geomID: ::std::os::raw::c_uint) -> RTCGeometry;
+}
+extern "C" {
+    pub fn rtcCommitScene(scene: RTCScene);
+}
+extern "C" {
+    pub fn rtcJoinCommitScene(scene: RTCScene);
+}
+pub type RTCProgressMonitorFunction =
+    ::std::option::Option<unsafe extern "C" fn(ptr: *mut ::std::os::raw::c_void, n: f64) -> bool>;
+extern "C" {
+    pub fn rtcSetSceneProgressMonitorFunction(
+        scene: RTCScene,
+        progress: RTCProgressMonitorFunction,
+        ptr: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn rtcSetSceneBuildQuality(scene: RTCScene, quality: RTCBuildQuality);
+}
+extern "C" {
+    pub fn rtcSetSceneFlags(scene: RTCScene, flags: RTCSceneFlags);
+}
+extern "C" {
+    pub fn rtcGetSceneFlags(scene: RTCScene) -> RTCSceneFlags;
+}
+extern "C" {
+    pub fn rtcGetSceneBounds(scene: RTCScene, bounds_o: *mut RTCBounds);
+}
+extern "C" {
+    pub fn rtcGetSceneLinearBounds(scene: RTCScene, bounds_o: *mut RTCLinearBounds);
+}
+extern "C" {
+    pub fn rtcPointQuery(
+        scene: RTCScene,
+        query: *mut RTCPointQuery,
+        context: *mut RTCPointQueryContext,
+        queryFunc: RTCPointQueryFunction,
+        userPtr: *mut ::std::os::raw::c_void,
+    ) -> bool;
+}
+extern "C" {
+    pub fn rtcPointQuery4(
+        valid: *const ::std::os::raw::c_int,
+        scene: RTCScene,
+        query: *mut RTCPointQuery4,
+        context: *mut RTCPointQueryContext,
+        queryFunc: RTCPointQueryFunction,
+        userPtr: *mut *mut ::std::os::raw::c_void,
+    ) -> bool;
+}
+extern "C" {
+    pub fn rtcPointQuery8(
+        valid: *const ::std::os::raw::c_int,
+        scene: RTCScene,
+        query: *mut RTCPointQuery8,
+        context: *mut RTCPointQueryContext,
+        queryFunc: RTCPointQueryFunction,
+        userPtr: *mut *mut ::std::os::raw::c_void,
+    ) -> bool;
+}
+extern "C" {
+    pub fn rtcPointQuery16(
+        valid: *const ::std::os::raw::c_int,
+        scene: RTCScene,
+        query: *mut RTCPointQuery16,
+        context: *mut RTCPointQueryContext,
+        queryFunc: RTCPointQueryFunction,
+        userPtr: *mut *mut ::std::os::raw::c_void,
+    ) -> bool;
+}
+extern "C" {
+    pub fn rtcIntersect1(scene: RTCScene, rayhit: *mut RTCRayHit, args: *mut RTCIntersectArguments);
+}
+extern "C" {
+    pub fn rtcIntersect4(
+        valid: *const ::std::os::raw::c_int,
+        scene: RTCScene,
+        rayhit: *mut RTCRayHit4,
+        args: *mut RTCIntersectArguments,
+    );
+}
+extern "C" {
+    pub fn rtcIntersect8(
+        valid: *const ::std::os::raw::c_int,
+        scene: RTCScene,
+        rayhit: *mut RTCRayHit8,
+        args: *mut RTCIntersectArguments,
+    );
+}
+extern "C" {
+    pub fn rtcIntersect16(
+        valid: *const ::std::os::raw::c_int,
+        scene: RTCScene,
+        rayhit: *mut RTCRayHit16,
+        args: *mut RTCIntersectArguments,
+    );
+}
+extern "C" {
+    pub fn rtcForwardIntersect1(
+        args: *const RTCIntersectFunctionNArguments,
+        scene: RTCScene,
+        ray: *mut RTCRay,
+        instID: ::std::os::raw::c_uint,
+    );
+}
+extern "C" {
+    pub fn rtcOccluded1(scene: RTCScene, ray: *mut RTCRay, args: *mut RTCOccludedArguments);
+}
+extern "C" {
+    pub fn rtcOccluded4(
+        valid: *const ::std::os::raw::c_int,
+        scene: RTCScene,
+        ray: *mut RTCRay4,
+        args: *mut RTCOccludedArguments,
+    );
+}
+extern "C" {
+    pub fn rtcOccluded8(
+        valid: *const ::std::os::raw::c_int,
+        scene: RTCScene,
+        ray: *mut RTCRay8,
+        args: *mut RTCOccludedArguments,
+    );
+}
+extern "C" {
+    pub fn rtcOccluded16(
+        valid: *const ::std::os::raw::c_int,
+        scene: RTCScene,
+        ray: *mut RTCRay16,
+        args: *mut RTCOccludedArguments,
+    );
+}
+extern "C" {
+    pub fn rtcForwardOccluded1(
+        args: *const RTCOccludedFunctionNArguments,
+        scene: RTCScene,
+        ray: *mut RTCRay,
+        instID: ::std::os::raw::c_uint,
+    );
+}
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCCollision {
+    pub geomID0: ::std::os::raw::c_uint,
+    pub primID0: ::std::os::raw::c_uint,
+    pub geomID1: ::std::os::raw::c_uint,
+    pub primID1: ::std::os::raw::c_uint,
+}
+#[test]
+fn bindgen_test_layout_RTCCollision() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCCollision>(),
+        16usize,
+        concat!("Size of: ", stringify!(RTCCollision))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCCollision>(),
+        4usize,
+        concat!("Alignment of ", stringify!(RTCCollision))
+    );
+    fn test_field_geomID0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCCollision>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).geomID0) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCCollision),
+                "::",
+                stringify!(geomID0)
+            )
+        );
+    }
+    test_field_geomID0();
+    fn test_field_primID0() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCCollision>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).primID0) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCCollision),
+                "::",
+                stringify!(primID0)
+            )
+        );
+    }
+    test_field_primID0();
+    fn test_field_geomID1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCCollision>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).geomID1) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCCollision),
+                "::",
+                stringify!(geomID1)
+            )
+        );
+    }
+    test_field_geomID1();
+    fn test_field_primID1() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCCollision>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).primID1) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCCollision),
+                "::",
+                stringify!(primID1)
+            )
+        );
+    }
+    test_field_primID1();
+}
+pub type RTCCollideFunc = ::std::option::Option<
+    unsafe extern "C" fn(
+        userPtr: *mut ::std::os::raw::c_void,
+        collisions: *mut RTCCollision,
+        num_collisions: ::std::os::raw::c_uint,
+    ),
+>;
+extern "C" {
+    pub fn rtcCollide(
+        scene0: RTCScene,
+        scene1: RTCScene,
+        callback: RTCCollideFunc,
+        userPtr: *mut ::std::os::raw::c_void,
+    );
+}
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCBVHTy {
+    _unused: [u8; 0],
+}
+pub type RTCBVH = *mut RTCBVHTy;
+#[repr(C)]
+#[repr(align(32))]
+#[derive(Debug)]
+pub struct RTCBuildPrimitive {
+    pub lower_x: f32,
+    pub lower_y: f32,
+    pub lower_z: f32,
+    pub geomID: ::std::os::raw::c_uint,
+    pub upper_x: f32,
+    pub upper_y: f32,
+    pub upper_z: f32,
+    pub primID: ::std::os::raw::c_uint,
+}
+#[test]
+fn bindgen_test_layout_RTCBuildPrimitive() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCBuildPrimitive>(),
+        32usize,
+        concat!("Size of: ", stringify!(RTCBuildPrimitive))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCBuildPrimitive>(),
+        32usize,
+        concat!("Alignment of ", stringify!(RTCBuildPrimitive))
+    );
+    fn test_field_lower_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildPrimitive>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).lower_x) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildPrimitive),
+                "::",
+                stringify!(lower_x)
+            )
+        );
+    }
+    test_field_lower_x();
+    fn test_field_lower_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildPrimitive>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).lower_y) as usize - ptr as usize
+            },
+            4usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildPrimitive),
+                "::",
+                stringify!(lower_y)
+            )
+        );
+    }
+    test_field_lower_y();
+    fn test_field_lower_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildPrimitive>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).lower_z) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildPrimitive),
+                "::",
+                stringify!(lower_z)
+            )
+        );
+    }
+    test_field_lower_z();
+    fn test_field_geomID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildPrimitive>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).geomID) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildPrimitive),
+                "::",
+                stringify!(geomID)
+            )
+        );
+    }
+    test_field_geomID();
+    fn test_field_upper_x() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildPrimitive>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).upper_x) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildPrimitive),
+                "::",
+                stringify!(upper_x)
+            )
+        );
+    }
+    test_field_upper_x();
+    fn test_field_upper_y() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildPrimitive>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).upper_y) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildPrimitive),
+                "::",
+                stringify!(upper_y)
+            )
+        );
+    }
+    test_field_upper_y();
+    fn test_field_upper_z() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildPrimitive>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).upper_z) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildPrimitive),
+                "::",
+                stringify!(upper_z)
+            )
+        );
+    }
+    test_field_upper_z();
+    fn test_field_primID() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildPrimitive>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).primID) as usize - ptr as usize
+            },
+            28usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildPrimitive),
+                "::",
+                stringify!(primID)
+            )
+        );
+    }
+    test_field_primID();
+}
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCThreadLocalAllocatorTy {
+    _unused: [u8; 0],
+}
+pub type RTCThreadLocalAllocator = *mut RTCThreadLocalAllocatorTy;
+pub type RTCCreateNodeFunction = ::std::option::Option<
+    unsafe extern "C" fn(
+        allocator: RTCThreadLocalAllocator,
+        childCount: ::std::os::raw::c_uint,
+        userPtr: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void,
+>;
+pub type RTCSetNodeChildrenFunction = ::std::option::Option<
+    unsafe extern "C" fn(
+        nodePtr: *mut ::std::os::raw::c_void,
+        children: *mut *mut ::std::os::raw::c_void,
+        childCount: ::std::os::raw::c_uint,
+        userPtr: *mut ::std::os::raw::c_void,
+    ),
+>;
+pub type RTCSetNodeBoundsFunction = ::std::option::Option<
+    unsafe extern "C" fn(
+        nodePtr: *mut ::std::os::raw::c_void,
+        bounds: *mut *const RTCBounds,
+        childCount: ::std::os::raw::c_uint,
+        userPtr: *mut ::std::os::raw::c_void,
+    ),
+>;
+pub type RTCCreateLeafFunction = ::std::option::Option<
+    unsafe extern "C" fn(
+        allocator: RTCThreadLocalAllocator,
+        primitives: *const RTCBuildPrimitive,
+        primitiveCount: usize,
+        userPtr: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void,
+>;
+pub type RTCSplitPrimitiveFunction = ::std::option::Option<
+    unsafe extern "C" fn(
+        primitive: *const RTCBuildPrimitive,
+        dimension: ::std::os::raw::c_uint,
+        position: f32,
+        leftBounds: *mut RTCBounds,
+        rightBounds: *mut RTCBounds,
+        userPtr: *mut ::std::os::raw::c_void,
+    ),
+>;
+pub const RTCBuildFlags_RTC_BUILD_FLAG_NONE: RTCBuildFlags = 0;
+pub const RTCBuildFlags_RTC_BUILD_FLAG_DYNAMIC: RTCBuildFlags = 1;
+pub type RTCBuildFlags = ::std::os::raw::c_uint;
+pub const RTCBuildConstants_RTC_BUILD_MAX_PRIMITIVES_PER_LEAF: RTCBuildConstants = 32;
+pub type RTCBuildConstants = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug)]
+pub struct RTCBuildArguments {
+    pub byteSize: usize,
+    pub buildQuality: RTCBuildQuality,
+    pub buildFlags: RTCBuildFlags,
+    pub maxBranchingFactor: ::std::os::raw::c_uint,
+    pub maxDepth: ::std::os::raw::c_uint,
+    pub sahBlockSize: ::std::os::raw::c_uint,
+    pub minLeafSize: ::std::os::raw::c_uint,
+    pub maxLeafSize: ::std::os::raw::c_uint,
+    pub traversalCost: f32,
+    pub intersectionCost: f32,
+    pub bvh: RTCBVH,
+    pub primitives: *mut RTCBuildPrimitive,
+    pub primitiveCount: usize,
+    pub primitiveArrayCapacity: usize,
+    pub createNode: RTCCreateNodeFunction,
+    pub setNodeChildren: RTCSetNodeChildrenFunction,
+    pub setNodeBounds: RTCSetNodeBoundsFunction,
+    pub createLeaf: RTCCreateLeafFunction,
+    pub splitPrimitive: RTCSplitPrimitiveFunction,
+    pub buildProgress: RTCProgressMonitorFunction,
+    pub userPtr: *mut ::std::os::raw::c_void,
+}
+#[test]
+fn bindgen_test_layout_RTCBuildArguments() {
+    assert_eq!(
+        ::std::mem::size_of::<RTCBuildArguments>(),
+        136usize,
+        concat!("Size of: ", stringify!(RTCBuildArguments))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RTCBuildArguments>(),
+        8usize,
+        concat!("Alignment of ", stringify!(RTCBuildArguments))
+    );
+    fn test_field_byteSize() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).byteSize) as usize - ptr as usize
+            },
+            0usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(byteSize)
+            )
+        );
+    }
+    test_field_byteSize();
+    fn test_field_buildQuality() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).buildQuality) as usize - ptr as usize
+            },
+            8usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(buildQuality)
+            )
+        );
+    }
+    test_field_buildQuality();
+    fn test_field_buildFlags() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).buildFlags) as usize - ptr as usize
+            },
+            12usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(buildFlags)
+            )
+        );
+    }
+    test_field_buildFlags();
+    fn test_field_maxBranchingFactor() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).maxBranchingFactor) as usize - ptr as usize
+            },
+            16usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(maxBranchingFactor)
+            )
+        );
+    }
+    test_field_maxBranchingFactor();
+    fn test_field_maxDepth() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).maxDepth) as usize - ptr as usize
+            },
+            20usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(maxDepth)
+            )
+        );
+    }
+    test_field_maxDepth();
+    fn test_field_sahBlockSize() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).sahBlockSize) as usize - ptr as usize
+            },
+            24usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(sahBlockSize)
+            )
+        );
+    }
+    test_field_sahBlockSize();
+    fn test_field_minLeafSize() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).minLeafSize) as usize - ptr as usize
+            },
+            28usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(minLeafSize)
+            )
+        );
+    }
+    test_field_minLeafSize();
+    fn test_field_maxLeafSize() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).maxLeafSize) as usize - ptr as usize
+            },
+            32usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(maxLeafSize)
+            )
+        );
+    }
+    test_field_maxLeafSize();
+    fn test_field_traversalCost() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).traversalCost) as usize - ptr as usize
+            },
+            36usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(traversalCost)
+            )
+        );
+    }
+    test_field_traversalCost();
+    fn test_field_intersectionCost() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).intersectionCost) as usize - ptr as usize
+            },
+            40usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(intersectionCost)
+            )
+        );
+    }
+    test_field_intersectionCost();
+    fn test_field_bvh() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).bvh) as usize - ptr as usize
+            },
+            48usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(bvh)
+            )
+        );
+    }
+    test_field_bvh();
+    fn test_field_primitives() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).primitives) as usize - ptr as usize
+            },
+            56usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(primitives)
+            )
+        );
+    }
+    test_field_primitives();
+    fn test_field_primitiveCount() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).primitiveCount) as usize - ptr as usize
+            },
+            64usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(primitiveCount)
+            )
+        );
+    }
+    test_field_primitiveCount();
+    fn test_field_primitiveArrayCapacity() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).primitiveArrayCapacity) as usize - ptr as usize
+            },
+            72usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(primitiveArrayCapacity)
+            )
+        );
+    }
+    test_field_primitiveArrayCapacity();
+    fn test_field_createNode() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).createNode) as usize - ptr as usize
+            },
+            80usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(createNode)
+            )
+        );
+    }
+    test_field_createNode();
+    fn test_field_setNodeChildren() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).setNodeChildren) as usize - ptr as usize
+            },
+            88usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(setNodeChildren)
+            )
+        );
+    }
+    test_field_setNodeChildren();
+    fn test_field_setNodeBounds() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).setNodeBounds) as usize - ptr as usize
+            },
+            96usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(setNodeBounds)
+            )
+        );
+    }
+    test_field_setNodeBounds();
+    fn test_field_createLeaf() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).createLeaf) as usize - ptr as usize
+            },
+            104usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(createLeaf)
+            )
+        );
+    }
+    test_field_createLeaf();
+    fn test_field_splitPrimitive() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).splitPrimitive) as usize - ptr as usize
+            },
+            112usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(splitPrimitive)
+            )
+        );
+    }
+    test_field_splitPrimitive();
+    fn test_field_buildProgress() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).buildProgress) as usize - ptr as usize
+            },
+            120usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(buildProgress)
+            )
+        );
+    }
+    test_field_buildProgress();
+    fn test_field_userPtr() {
+        assert_eq!(
+            unsafe {
+                let uninit = ::std::mem::MaybeUninit::<RTCBuildArguments>::uninit();
+                let ptr = uninit.as_ptr();
+                ::std::ptr::addr_of!((*ptr).userPtr) as usize - ptr as usize
+            },
+            128usize,
+            concat!(
+                "Offset of field: ",
+                stringify!(RTCBuildArguments),
+                "::",
+                stringify!(userPtr)
+            )
+        );
+    }
+    test_field_userPtr();
+}
+extern "C" {
+    pub fn rtcNewBVH(device: RTCDevice) -> RTCBVH;
+}
+extern "C" {
+    pub fn rtcBuildBVH(args: *const RTCBuildArguments) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn rtcThreadLocalAlloc(
+        allocator: RTCThreadLocalAllocator,
+        bytes: usize,
+        align: usize,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn rtcMakeStaticBVH(bvh: RTCBVH);
+}
+extern "C" {
+    pub fn rtcRetainBVH(bvh: RTCBVH);
+}
+extern "C" {
+    pub fn rtcReleaseBVH(bvh: RTCBVH);
+}
@@ -0,0 +1,431 @@
+use std::os::raw::{c_uint, c_void};
+
+use crate::{sys, Aabb, Device, Embree, Vec3};
+
+/// Quality of the BVH to build, higher quality BVHs are slower to
+/// build but faster to traverse
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildQuality {
+    Low,
+    Medium,
+    /// Enables spatial splits of the primitives, so a primitive can
+    /// be referenced by multiple leaves.
+    High,
+}
+
+impl BuildQuality {
+    fn to_sys(self) -> sys::RTCBuildQuality {
+        match self {
+            BuildQuality::Low => sys::RTCBuildQuality_RTC_BUILD_QUALITY_LOW,
+            BuildQuality::Medium => sys::RTCBuildQuality_RTC_BUILD_QUALITY_MEDIUM,
+            BuildQuality::High => sys::RTCBuildQuality_RTC_BUILD_QUALITY_HIGH,
+        }
+    }
+}
+
+/// Options of the BVH builder, see [`Embree::build_bvh()`]
+///
+/// The default is the same as embree's `rtcDefaultBuildArguments()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BvhBuildConfig {
+    pub quality: BuildQuality,
+    /// Maximum number of children of an inner node.
+    pub max_branching_factor: u32,
+    /// Maximum depth of the BVH.
+    pub max_depth: u32,
+    /// Block size for the SAH heuristic, leaves are made of multiples
+    /// of this many primitives.
+    pub sah_block_size: u32,
+    /// Minimum number of primitives in a leaf.
+    pub min_leaf_size: u32,
+    /// Maximum number of primitives in a leaf.
+    pub max_leaf_size: u32,
+    /// Estimated cost of traversing a node for the SAH heuristic.
+    pub traversal_cost: f32,
+    /// Estimated cost of intersecting a primitive for the SAH
+    /// heuristic.
+    pub intersection_cost: f32,
+}
+
+impl Default for BvhBuildConfig {
+    fn default() -> Self {
+        Self {
+            quality: BuildQuality::Medium,
+            max_branching_factor: 2,
+            max_depth: 32,
+            sah_block_size: 1,
+            min_leaf_size: 1,
+            max_leaf_size: 32,
+            traversal_cost: 1.0,
+            intersection_cost: 1.0,
+        }
+    }
+}
+
+/// Primitive to build the BVH over, `id` identifies the primitive in
+/// the user's data
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BuildPrimitive {
+    pub bounds: Aabb,
+    pub id: u32,
+}
+
+impl BuildPrimitive {
+    pub fn new(bounds: Aabb, id: u32) -> Self {
+        Self { bounds, id }
+    }
+}
+
+impl From<&BuildPrimitive> for sys::RTCBuildPrimitive {
+    fn from(primitive: &BuildPrimitive) -> Self {
+        Self {
+            lower_x: primitive.bounds.min.x,
+            lower_y: primitive.bounds.min.y,
+            lower_z: primitive.bounds.min.z,
+            geomID: 0,
+            upper_x: primitive.bounds.max.x,
+            upper_y: primitive.bounds.max.y,
+            upper_z: primitive.bounds.max.z,
+            primID: primitive.id,
+        }
+    }
+}
+
+impl From<&sys::RTCBuildPrimitive> for BuildPrimitive {
+    fn from(primitive: &sys::RTCBuildPrimitive) -> Self {
+        Self {
+            bounds: Aabb::new(
+                Vec3::new(primitive.lower_x, primitive.lower_y, primitive.lower_z),
+                Vec3::new(primitive.upper_x, primitive.upper_y, primitive.upper_z),
+            ),
+            id: primitive.primID,
+        }
+    }
+}
+
+impl Embree {
+    /// Build a BVH over the primitives with embree's SAH builder.
+    ///
+    /// The tree is made of the user's node type `T`, `create_leaf`
+    /// creates a leaf from the primitives of the leaf and
+    /// `create_node` creates an inner node from its children along
+    /// with the bounds of each child. The tree is built bottom up,
+    /// so the children are created before their parent.
+    ///
+    /// Returns the root of the tree, [`None`] if there are no
+    /// primitives.
+    pub fn build_bvh<T>(
+        &self,
+        config: &BvhBuildConfig,
+        primitives: &[BuildPrimitive],
+        mut create_node: impl FnMut(Vec<(T, Aabb)>) -> T,
+        mut create_leaf: impl FnMut(&[BuildPrimitive]) -> T,
+    ) -> Option<T> {
+        if primitives.is_empty() {
+            return None;
+        }
+
+        let bvh = Bvh::new(&self.device);
+
+        let mut build_primitives: Vec<sys::RTCBuildPrimitive> = primitives
+            .iter()
+            .map(|primitive| primitive.into())
+            .collect();
+        // spatial splits require extra space to store the split
+        // primitives
+        if config.quality == BuildQuality::High {
+            build_primitives.reserve(primitives.len());
+        }
+
+        let args = sys::RTCBuildArguments {
+            byteSize: std::mem::size_of::<sys::RTCBuildArguments>(),
+            buildQuality: config.quality.to_sys(),
+            buildFlags: sys::RTCBuildFlags_RTC_BUILD_FLAG_NONE,
+            maxBranchingFactor: config.max_branching_factor,
+            maxDepth: config.max_depth,
+            sahBlockSize: config.sah_block_size,
+            minLeafSize: config.min_leaf_size,
+            maxLeafSize: config.max_leaf_size,
+            traversalCost: config.traversal_cost,
+            intersectionCost: config.intersection_cost,
+            bvh: bvh.bvh,
+            primitives: build_primitives.as_mut_ptr(),
+            primitiveCount: build_primitives.len(),
+            primitiveArrayCapacity: build_primitives.capacity(),
+            createNode: Some(create_node_func),
+            setNodeChildren: Some(set_node_children_func),
+            setNodeBounds: Some(set_node_bounds_func),
+            createLeaf: Some(create_leaf_func),
+            splitPrimitive: Some(split_primitive_func),
+            buildProgress: None,
+            userPtr: std::ptr::null_mut(),
+        };
+
+        let root = unsafe { sys::rtcBuildBVH(&args) };
+        assert_ne!(root, std::ptr::null_mut());
+
+        // the intermediate tree lives in the memory of the BVH, it
+        // is converted to the user's tree before the BVH is released
+        Some(unsafe { convert_node(root, &mut create_node, &mut create_leaf) })
+    }
+}
+
+/// BVH object of embree, owns the memory of the intermediate tree
+struct Bvh {
+    bvh: sys::RTCBVH,
+}
+
+impl Bvh {
+    fn new(device: &Device) -> Self {
+        let bvh = unsafe { sys::rtcNewBVH(device.get_device()) };
+        assert_ne!(bvh, std::ptr::null_mut());
+        Self { bvh }
+    }
+}
+
+impl Drop for Bvh {
+    fn drop(&mut self) {
+        unsafe {
+            sys::rtcReleaseBVH(self.bvh);
+        }
+        self.bvh = std::ptr::null_mut();
+    }
+}
+
+/// Inner node of the intermediate tree built by embree
+///
+/// Allocated with `rtcThreadLocalAlloc()`, so it must not own any
+/// Rust memory.
+#[repr(C)]
+struct InnerNode {
+    is_leaf: bool,
+    num_children: usize,
+    children: *mut *mut c_void,
+    bounds: *mut Aabb,
+}
+
+/// Leaf node of the intermediate tree built by embree
+///
+/// Allocated with `rtcThreadLocalAlloc()`, so it must not own any
+/// Rust memory.
+#[repr(C)]
+struct LeafNode {
+    is_leaf: bool,
+    num_primitives: usize,
+    primitives: *mut sys::RTCBuildPrimitive,
+}
+
+/// Allocate space for `count` elements of `T` from the thread local
+/// allocator of the BVH.
+unsafe fn thread_local_alloc<T>(allocator: sys::RTCThreadLocalAllocator, count: usize) -> *mut T {
+    let ptr = sys::rtcThreadLocalAlloc(
+        allocator,
+        std::mem::size_of::<T>() * count,
+        std::mem::align_of::<T>(),
+    ) as *mut T;
+    assert_ne!(ptr, std::ptr::null_mut());
+    ptr
+}
+
+unsafe extern "C" fn create_node_func(
+    allocator: sys::RTCThreadLocalAllocator,
+    child_count: c_uint,
+    _user_ptr: *mut c_void,
+) -> *mut c_void {
+    let num_children = child_count as usize;
+    let node = thread_local_alloc::<InnerNode>(allocator, 1);
+    node.write(InnerNode {
+        is_leaf: false,
+        num_children,
+        children: thread_local_alloc(allocator, num_children),
+        bounds: thread_local_alloc(allocator, num_children),
+    });
+    node as *mut c_void
+}
+
+unsafe extern "C" fn set_node_children_func(
+    node_ptr: *mut c_void,
+    children: *mut *mut c_void,
+    child_count: c_uint,
+    _user_ptr: *mut c_void,
+) {
+    let node = &mut *(node_ptr as *mut InnerNode);
+    debug_assert_eq!(node.num_children, child_count as usize);
+    std::ptr::copy_nonoverlapping(children, node.children, child_count as usize);
+}
+
+unsafe extern "C" fn set_node_bounds_func(
+    node_ptr: *mut c_void,
+    bounds: *mut *const sys::RTCBounds,
+    child_count: c_uint,
+    _user_ptr: *mut c_void,
+) {
+    let node = &mut *(node_ptr as *mut InnerNode);
+    debug_assert_eq!(node.num_children, child_count as usize);
+    std::slice::from_raw_parts(bounds, child_count as usize)
+        .iter()
+        .enumerate()
+        .for_each(|(i, bounds)| {
+            let bounds = &**bounds;
+            node.bounds.add(i).write(Aabb::new(
+                Vec3::new(bounds.lower_x, bounds.lower_y, bounds.lower_z),
+                Vec3::new(bounds.upper_x, bounds.upper_y, bounds.upper_z),
+            ));
+        });
+}
+
+unsafe extern "C" fn create_leaf_func(
+    allocator: sys::RTCThreadLocalAllocator,
+    primitives: *const sys::RTCBuildPrimitive,
+    num_primitives: usize,
+    _user_ptr: *mut c_void,
+) -> *mut c_void {
+    let node = thread_local_alloc::<LeafNode>(allocator, 1);
+    let leaf_primitives = thread_local_alloc(allocator, num_primitives);
+    std::ptr::copy_nonoverlapping(primitives, leaf_primitives, num_primitives);
+    node.write(LeafNode {
+        is_leaf: true,
+        num_primitives,
+        primitives: leaf_primitives,
+    });
+    node as *mut c_void
+}
+
+/// Split the bounds of the primitive at `position` along the axis
+/// `dimension`, used for spatial splits.
+unsafe extern "C" fn split_primitive_func(
+    primitive: *const sys::RTCBuildPrimitive,
+    dimension: c_uint,
+    position: f32,
+    left_bounds: *mut sys::RTCBounds,
+    right_bounds: *mut sys::RTCBounds,
+    _user_ptr: *mut c_void,
+) {
+    let primitive = &*primitive;
+    let left_bounds = &mut *left_bounds;
+    let right_bounds = &mut *right_bounds;
+
+    let lower = [primitive.lower_x, primitive.lower_y, primitive.lower_z];
+    let upper = [primitive.upper_x, primitive.upper_y, primitive.upper_z];
+
+    let mut left_upper = upper;
+    let mut right_lower = lower;
+    left_upper[dimension as usize] = position;
+    right_lower[dimension as usize] = position;
+
+    left_bounds.lower_x = lower[0];
+    left_bounds.lower_y = lower[1];
+    left_bounds.lower_z = lower[2];
+    left_bounds.upper_x = left_upper[0];
+    left_bounds.upper_y = left_upper[1];
+    left_bounds.upper_z = left_upper[2];
+
+    right_bounds.lower_x = right_lower[0];
+    right_bounds.lower_y = right_lower[1];
+    right_bounds.lower_z = right_lower[2];
+    right_bounds.upper_x = upper[0];
+    right_bounds.upper_y = upper[1];
+    right_bounds.upper_z = upper[2];
+}
+
+/// Convert the intermediate tree built by embree to the user's tree,
+/// bottom up.
+///
+/// # Safety
+///
+/// `node` must be a node created by [`create_node_func()`] or
+/// [`create_leaf_func()`] whose BVH is still alive.
+unsafe fn convert_node<T>(
+    node: *mut c_void,
+    create_node: &mut impl FnMut(Vec<(T, Aabb)>) -> T,
+    create_leaf: &mut impl FnMut(&[BuildPrimitive]) -> T,
+) -> T {
+    // both the node types start with `is_leaf`
+    if *(node as *const bool) {
+        let leaf = &*(node as *const LeafNode);
+        let primitives: Vec<BuildPrimitive> =
+            std::slice::from_raw_parts(leaf.primitives, leaf.num_primitives)
+                .iter()
+                .map(|primitive| primitive.into())
+                .collect();
+        create_leaf(&primitives)
+    } else {
+        let inner = &*(node as *const InnerNode);
+        let children = std::slice::from_raw_parts(inner.children, inner.num_children);
+        let bounds = std::slice::from_raw_parts(inner.bounds, inner.num_children);
+        let children = children
+            .iter()
+            .zip(bounds)
+            .map(|(child, bounds)| (convert_node(*child, create_node, create_leaf), *bounds))
+            .collect();
+        create_node(children)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BuildPrimitive, BuildQuality, BvhBuildConfig};
+    use crate::{Aabb, Embree, Vec3};
+
+    #[derive(Debug)]
+    enum Node {
+        Inner(Vec<(Node, Aabb)>),
+        Leaf(Vec<u32>),
+    }
+
+    impl Node {
+        fn ids(&self) -> Vec<u32> {
+            match self {
+                Node::Inner(children) => {
+                    children.iter().flat_map(|(child, _)| child.ids()).collect()
+                }
+                Node::Leaf(ids) => ids.clone(),
+            }
+        }
+    }
+
+    /// Every primitive must end up in the tree built by the user's
+    /// closures
+    #[test]
+    fn build_bvh() {
+        let embree = Embree::new();
+
+        let primitives: Vec<BuildPrimitive> = (0..100)
+            .map(|i| {
+                let min = Vec3::new(i as f32, (i % 10) as f32, 0.0);
+                BuildPrimitive::new(Aabb::new(min, min + Vec3::new(0.5, 0.5, 0.5)), i)
+            })
+            .collect();
+
+        [BuildQuality::Low, BuildQuality::Medium, BuildQuality::High]
+            .iter()
+            .for_each(|quality| {
+                let config = BvhBuildConfig {
+                    quality: *quality,
+                    max_leaf_size: 4,
+                    ..Default::default()
+                };
+
+                let root = embree
+                    .build_bvh(&config, &primitives, Node::Inner, |primitives| {
+                        assert!(primitives.len() <= 4);
+                        Node::Leaf(primitives.iter().map(|primitive| primitive.id).collect())
+                    })
+                    .unwrap();
+
+                let mut ids = root.ids();
+                ids.sort_unstable();
+                ids.dedup();
+                assert_eq!(ids, (0..100).collect::<Vec<_>>());
+            });
+
+        assert!(embree
+            .build_bvh(
+                &BvhBuildConfig::default(),
+                &[],
+                Node::Inner,
+                |_| Node::Leaf(vec![])
+            )
+            .is_none());
+    }
+}
@@ -0,0 +1,391 @@
+use std::os::raw::c_void;
+
+use crate::collide::{CollisionScene, Primitive};
+use crate::point_query::closest_point_on_triangle;
+use crate::{
+    sys, Embree, Error, Geometry, GeometrySceneID, PointQuery, PointQueryContext, PrimRef, SceneID,
+    Vec3,
+};
+
+/// First contact of a moving vertex with the surface of a scene
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeOfImpact {
+    /// Time of the first contact within the time range `[0, 1]`.
+    pub time: f32,
+    /// Position of the vertex at the time of contact.
+    pub point: Vec3,
+    /// Normalized normal of the surface at the time of contact,
+    /// facing the side from which the vertex approaches.
+    pub normal: Vec3,
+    /// Primitive that the vertex comes in contact with.
+    pub prim_ref: PrimRef,
+}
+
+impl Embree {
+    /// Find the first contact of each of the vertices moving
+    /// linearly from `start` to `end` (over the time range `[0, 1]`)
+    /// with the triangles of the scene of the given id.
+    ///
+    /// The triangles can move as well, see
+    /// [`Self::add_geometry_triangle_motion()`], the vertices of the
+    /// triangles are linearly interpolated between the time steps.
+    /// Only triangle geometries are considered.
+    ///
+    /// The BVH of the swept bounds of the primitives is built once
+    /// per call, so all the vertices should be queried together.
+    ///
+    /// Returns [`Error::SceneNotFound`], [`Error::SceneNotCommitted`]
+    /// or [`Error::SceneCommitting`] if the scene cannot be queried.
+    pub fn time_of_impact(
+        &self,
+        scene_id: SceneID,
+        vertex_paths: &[(Vec3, Vec3)],
+    ) -> Result<Vec<Option<TimeOfImpact>>, Error> {
+        let scene = self.get_committed_scene(scene_id)?;
+
+        let collision_scene = CollisionScene::from_scene(self, scene);
+
+        Ok(vertex_paths
+            .iter()
+            .map(|(start, end)| {
+                swept_candidates(&collision_scene, *start, *end)
+                    .into_iter()
+                    .filter_map(|(geometry_scene_id, primitive_index)| {
+                        let geometry_id = *scene
+                            .attached_geometries
+                            .get(&geometry_scene_id)
+                            .expect("geometry must be attached to the scene");
+                        let geometry = self
+                            .geometries
+                            .get(geometry_id.0)
+                            .expect("geometry of given id is not available");
+
+                        primitive_time_of_impact(geometry, primitive_index, *start, *end).map(
+                            |(time, point, normal)| TimeOfImpact {
+                                time,
+                                point,
+                                normal,
+                                prim_ref: PrimRef {
+                                    geometry_id,
+                                    primitive_index,
+                                },
+                            },
+                        )
+                    })
+                    .min_by(|a, b| a.time.total_cmp(&b.time))
+            })
+            .collect())
+    }
+}
+
+/// Primitives of the collision scene whose swept bounds may contain
+/// the segment from `start` to `end`.
+fn swept_candidates(
+    collision_scene: &CollisionScene,
+    start: Vec3,
+    end: Vec3,
+) -> Vec<(GeometrySceneID, u32)> {
+    let center = (start + end) * 0.5;
+    let mut query = PointQuery {
+        x: center.x,
+        y: center.y,
+        z: center.z,
+        time: 0.0,
+        // sphere enclosing the segment, slightly enlarged so the
+        // end points are not lost to round off
+        radius: (end - start).length() * 0.5 * (1.0 + 1e-4) + 1e-6,
+    };
+
+    let mut context = PointQueryContext::default();
+
+    let mut candidates: Vec<(GeometrySceneID, u32)> = Vec::new();
+
+    unsafe {
+        sys::rtcPointQuery(
+            collision_scene.get_scene(),
+            &mut query,
+            &mut context,
+            Some(swept_candidates_query_func),
+            &mut candidates as *mut Vec<(GeometrySceneID, u32)> as *mut c_void,
+        );
+    }
+
+    candidates
+}
+
+/// Point query function that collects every primitive whose bounds
+/// overlap the query sphere.
+unsafe extern "C" fn swept_candidates_query_func(
+    args: *mut sys::RTCPointQueryFunctionArguments,
+) -> bool {
+    let args = &*args;
+    let candidates = &mut *(args.userPtr as *mut Vec<(GeometrySceneID, u32)>);
+    candidates.push((GeometrySceneID(args.geomID), args.primID));
+    false
+}
+
+/// Time of impact of the vertex moving from `start` to `end` with
+/// the primitive of the given index, along with the position of the
+/// vertex and the normal at the time of impact.
+fn primitive_time_of_impact(
+    geometry: &Geometry,
+    primitive_index: u32,
+    start: Vec3,
+    end: Vec3,
+) -> Option<(f32, Vec3, Vec3)> {
+    let num_time_steps = geometry.num_time_steps();
+    // a geometry without motion is a single segment that does not
+    // move
+    let num_segments = (num_time_steps - 1).max(1);
+
+    (0..num_segments).find_map(|segment| {
+        let triangle_at = |time_step: u32| match geometry
+            .get_primitive_at_time_step(primitive_index, time_step.min(num_time_steps - 1))
+        {
+            Primitive::Triangle(triangle) => Some(triangle),
+            Primitive::Quad(_) | Primitive::Sphere(_, _) => None,
+        };
+
+        let t0 = segment as f32 / num_segments as f32;
+        let t1 = (segment + 1) as f32 / num_segments as f32;
+
+        let vertex = [start + (end - start) * t0, start + (end - start) * t1];
+        let triangle = [triangle_at(segment)?, triangle_at(segment + 1)?];
+
+        vertex_triangle_time_of_impact(vertex, triangle)
+            .map(|(time, point, normal)| (t0 + time * (t1 - t0), point, normal))
+    })
+}
+
+/// Earliest time within `[0, 1]` at which the vertex, moving
+/// linearly from `vertex[0]` to `vertex[1]`, lies on the triangle,
+/// moving linearly from `triangle[0]` to `triangle[1]`. Also returns
+/// the position of the vertex and the normal of the triangle facing
+/// the vertex at that time.
+///
+/// The vertex can only lie on the triangle when the vertex and the
+/// triangle are coplanar, which is a cubic equation in time. Each
+/// root of the cubic is then checked for the vertex being within the
+/// triangle.
+pub(crate) fn vertex_triangle_time_of_impact(
+    vertex: [Vec3; 2],
+    triangle: [[Vec3; 3]; 2],
+) -> Option<(f32, Vec3, Vec3)> {
+    /// Tolerance (relative to the size of the triangle) within which
+    /// the vertex is considered to be on the triangle.
+    const TOLERANCE: f32 = 1e-4;
+
+    let [a0, b0, c0] = triangle[0];
+    let [a1, b1, c1] = triangle[1];
+    let (da, db, dc, dp) = (a1 - a0, b1 - b0, c1 - c0, vertex[1] - vertex[0]);
+
+    // edges and vertex relative to `a` as linear functions of time
+    // `x(t) = x0 + t * x1`
+    let (e1_0, e1_1) = (b0 - a0, db - da);
+    let (e2_0, e2_1) = (c0 - a0, dc - da);
+    let (p_0, p_1) = (vertex[0] - a0, dp - da);
+
+    // unnormalized normal as a quadratic function of time
+    let n_0 = e1_0.cross(&e2_0);
+    let n_1 = e1_0.cross(&e2_1) + e1_1.cross(&e2_0);
+    let n_2 = e1_1.cross(&e2_1);
+
+    let coefficients = [
+        n_0.dot(&p_0),
+        n_0.dot(&p_1) + n_1.dot(&p_0),
+        n_1.dot(&p_1) + n_2.dot(&p_0),
+        n_2.dot(&p_1),
+    ];
+
+    cubic_roots_in_unit_interval(coefficients)
+        .into_iter()
+        .find_map(|time| {
+            let (a, b, c) = (a0 + da * time, b0 + db * time, c0 + dc * time);
+            let point = vertex[0] + dp * time;
+
+            let normal = (b - a).cross(&(c - a));
+            if normal.length() == 0.0 {
+                // degenerate triangle
+                return None;
+            }
+
+            let (closest, (u, v)) = closest_point_on_triangle(point, a, b, c);
+            let size = (b - a).length().max((c - a).length());
+            if (closest - point).length() > TOLERANCE * size {
+                return None;
+            }
+
+            // velocity of the vertex relative to the triangle at the
+            // point of contact
+            let relative_velocity = dp - (da * (1.0 - u - v) + db * u + dc * v);
+            let normal = normal.normalize();
+            let normal = if normal.dot(&relative_velocity) > 0.0 {
+                -normal
+            } else {
+                normal
+            };
+
+            Some((time, point, normal))
+        })
+}
+
+/// Roots of the cubic `c[3] * t^3 + c[2] * t^2 + c[1] * t + c[0]`
+/// within `[0, 1]` in ascending order.
+///
+/// `[0, 1]` is split at the roots of the derivative so that the
+/// cubic is monotonic within each interval, the root within each
+/// interval (if any) is then found by bisection.
+pub(crate) fn cubic_roots_in_unit_interval(coefficients: [f32; 4]) -> Vec<f32> {
+    let [c0, c1, c2, c3] = coefficients.map(f64::from);
+    let f = |t: f64| ((c3 * t + c2) * t + c1) * t + c0;
+
+    // roots of the derivative `3 * c3 * t^2 + 2 * c2 * t + c1`
+    let (a, b, c) = (3.0 * c3, 2.0 * c2, c1);
+    let mut critical_points = if a != 0.0 {
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant >= 0.0 {
+            let sqrt_discriminant = discriminant.sqrt();
+            vec![
+                (-b - sqrt_discriminant) / (2.0 * a),
+                (-b + sqrt_discriminant) / (2.0 * a),
+            ]
+        } else {
+            vec![]
+        }
+    } else if b != 0.0 {
+        vec![-c / b]
+    } else {
+        vec![]
+    };
+    critical_points.sort_by(|a, b| a.total_cmp(b));
+
+    let breakpoints: Vec<f64> = std::iter::once(0.0)
+        .chain(critical_points.into_iter().filter(|t| *t > 0.0 && *t < 1.0))
+        .chain(std::iter::once(1.0))
+        .collect();
+
+    let mut roots: Vec<f32> = breakpoints
+        .windows(2)
+        .filter_map(|interval| {
+            let (mut low, mut high) = (interval[0], interval[1]);
+            let (f_low, f_high) = (f(low), f(high));
+
+            if f_low == 0.0 {
+                return Some(low as f32);
+            }
+            if f_high == 0.0 || f_low.signum() == f_high.signum() {
+                // root at `high` is found by the next interval or
+                // after the loop
+                return None;
+            }
+
+            (0..64).for_each(|_| {
+                let mid = 0.5 * (low + high);
+                if f(mid).signum() == f_low.signum() {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            });
+
+            Some((0.5 * (low + high)) as f32)
+        })
+        .collect();
+
+    if f(1.0) == 0.0 {
+        roots.push(1.0);
+    }
+
+    roots.dedup();
+    roots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cubic_roots_in_unit_interval;
+    use crate::{Embree, Error, Triangle, Vec3, Vert};
+
+    #[test]
+    fn cubic_roots() {
+        // (t - 0.25) * (t - 0.5) * (t - 2)
+        let roots = cubic_roots_in_unit_interval([-0.25, 1.625, -2.75, 1.0]);
+        assert_eq!(roots.len(), 2);
+        assert!((roots[0] - 0.25).abs() < 1e-6);
+        assert!((roots[1] - 0.5).abs() < 1e-6);
+
+        // linear, 2 * t - 1
+        assert_eq!(
+            cubic_roots_in_unit_interval([-1.0, 2.0, 0.0, 0.0]),
+            vec![0.5]
+        );
+
+        // no roots
+        assert!(cubic_roots_in_unit_interval([1.0, 0.0, 1.0, 0.0]).is_empty());
+    }
+
+    /// Vertices moving through static and moving triangles must hit
+    /// at the correct time
+    #[test]
+    fn time_of_impact() {
+        let mut embree = Embree::new();
+        let scene_id = embree.add_scene();
+
+        let triangle = |z| {
+            [
+                Vert::new(Vec3::new(0.0, 0.0, z)),
+                Vert::new(Vec3::new(1.0, 0.0, z)),
+                Vert::new(Vec3::new(0.0, 1.0, z)),
+            ]
+        };
+        // triangle moving from z = -1 to z = 1
+        let moving_id = embree.add_geometry_triangle_motion(
+            &[&triangle(-1.0), &triangle(1.0)],
+            &[Triangle::new(0, 1, 2)],
+        );
+        // static triangle at z = 0, offset along x
+        let static_id = embree.add_geometry_triangle(
+            &[
+                Vert::new(Vec3::new(5.0, 0.0, 0.0)),
+                Vert::new(Vec3::new(6.0, 0.0, 0.0)),
+                Vert::new(Vec3::new(5.0, 1.0, 0.0)),
+            ],
+            &[Triangle::new(0, 1, 2)],
+        );
+        embree.attach_geometry_to_scene(moving_id, scene_id);
+        embree.attach_geometry_to_scene(static_id, scene_id);
+        let scene_id = embree.commit_scene(scene_id);
+
+        let time_of_impacts = embree
+            .time_of_impact(
+                scene_id,
+                &[
+                    // static vertex, moving triangle comes up to it
+                    (Vec3::new(0.25, 0.25, 0.5), Vec3::new(0.25, 0.25, 0.5)),
+                    // vertex moving down through the static triangle
+                    (Vec3::new(5.25, 0.25, 1.0), Vec3::new(5.25, 0.25, -1.0)),
+                    // vertex that misses everything
+                    (Vec3::new(3.0, 3.0, 1.0), Vec3::new(3.0, 3.0, -1.0)),
+                ],
+            )
+            .unwrap();
+
+        let moving = time_of_impacts[0].unwrap();
+        assert_eq!(moving.prim_ref.geometry_id, moving_id);
+        assert!((moving.time - 0.75).abs() < 1e-5);
+        assert!((moving.normal - Vec3::new(0.0, 0.0, 1.0)).length() < 1e-5);
+
+        let fixed = time_of_impacts[1].unwrap();
+        assert_eq!(fixed.prim_ref.geometry_id, static_id);
+        assert!((fixed.time - 0.5).abs() < 1e-5);
+        assert!((fixed.point - Vec3::new(5.25, 0.25, 0.0)).length() < 1e-5);
+        assert!((fixed.normal - Vec3::new(0.0, 0.0, 1.0)).length() < 1e-5);
+
+        assert!(time_of_impacts[2].is_none());
+
+        let uncommitted_scene_id = embree.add_scene();
+        assert_eq!(
+            embree.time_of_impact(uncommitted_scene_id, &[]),
+            Err(Error::SceneNotCommitted)
+        );
+    }
+}
@@ -0,0 +1,627 @@
+use std::os::raw::c_void;
+use std::sync::Mutex;
+
+use crate::point_query::{closest_point_on_triangle, quad_triangles};
+use crate::{
+    new_geometry, sys, Aabb, Device, Embree, Error, Geometry, GeometryID, GeometrySceneID, PrimRef,
+    SceneCommitted, SceneID, Sphere, Vec3,
+};
+
+/// Pair of primitives whose bounds overlap (or that intersect when
+/// the exact narrow phase is used)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Collision {
+    /// Primitive of the first scene.
+    pub prim_a: PrimRef,
+    /// Primitive of the second scene.
+    pub prim_b: PrimRef,
+}
+
+impl Embree {
+    /// Find the pairs of primitives of the scenes of the given ids
+    /// that collide, `callback` is called for every such pair.
+    ///
+    /// Without `exact`, all the pairs of primitives whose bounding
+    /// boxes overlap are reported (broad phase). With `exact`, only
+    /// the pairs of primitives that actually intersect are reported.
+    /// Triangles are tested by intersecting the edges of each
+    /// triangle with the other triangle, so coplanar triangles are
+    /// only reported if their edges cross.
+    ///
+    /// Passing the same scene twice detects the self collisions of
+    /// the scene. Disabled geometries are skipped, like by all the
+    /// other queries.
+    ///
+    /// Embree can only collide user geometries, so the BVH of
+    /// primitive bounds of both the scenes is built on every call.
+    ///
+    /// Returns [`Error::SceneNotFound`], [`Error::SceneNotCommitted`]
+    /// or [`Error::SceneCommitting`] if one of the scenes cannot be
+    /// queried, `callback` is not called in that case.
+    pub fn collide(
+        &self,
+        scene_a: SceneID,
+        scene_b: SceneID,
+        exact: bool,
+        callback: impl FnMut(Collision),
+    ) -> Result<(), Error> {
+        let scene_a = self.get_committed_scene(scene_a)?;
+        let scene_b = self.get_committed_scene(scene_b)?;
+
+        self.collide_scenes(scene_a, scene_b)
+            .into_iter()
+            .filter(|collision| {
+                !exact
+                    || self
+                        .get_primitive(collision.prim_a)
+                        .intersects(&self.get_primitive(collision.prim_b))
+            })
+            .for_each(callback);
+
+        Ok(())
+    }
+
+    /// Broad phase collision of the scenes, all the pairs of
+    /// primitives whose bounds overlap.
+    pub(crate) fn collide_scenes(
+        &self,
+        scene_a: &SceneCommitted,
+        scene_b: &SceneCommitted,
+    ) -> Vec<Collision> {
+        let collision_scene_a = CollisionScene::from_scene(self, scene_a);
+        let collisions = if std::ptr::eq(scene_a, scene_b) {
+            collision_scene_a.collide(&collision_scene_a)
+        } else {
+            collision_scene_a.collide(&CollisionScene::from_scene(self, scene_b))
+        };
+
+        let to_prim_ref = |scene: &SceneCommitted, geometry_scene_id, primitive_index| PrimRef {
+            geometry_id: *scene
+                .attached_geometries
+                .get(&GeometrySceneID(geometry_scene_id))
+                .expect("geometry must be attached to the scene"),
+            primitive_index,
+        };
+
+        collisions
+            .into_iter()
+            .map(|collision| Collision {
+                prim_a: to_prim_ref(scene_a, collision.geomID0, collision.primID0),
+                prim_b: to_prim_ref(scene_b, collision.geomID1, collision.primID1),
+            })
+            .collect()
+    }
+
+    /// Find the pairs of triangles of the triangle geometry of the
+    /// given id that intersect each other, each pair is reported once
+    /// as `(smaller index, larger index)` in sorted order.
+    ///
+    /// Adjacent triangles, triangles that share a vertex, always
+    /// touch so they are not reported. For other kinds of geometries
+    /// all the pairs of intersecting primitives are reported.
+    ///
+    /// Returns [`Error::GeometryNotFound`] if there is no geometry of
+    /// the given id.
+    pub fn self_intersections(&self, geometry_id: GeometryID) -> Result<Vec<(u32, u32)>, Error> {
+        let geometry = self
+            .geometries
+            .get(geometry_id.0)
+            .ok_or(Error::GeometryNotFound)?;
+
+        let collision_scene = CollisionScene::new(
+            &self.device,
+            std::iter::once((GeometrySceneID(0), geometry)),
+        );
+
+        let mut intersections: Vec<(u32, u32)> = collision_scene
+            .collide(&collision_scene)
+            .into_iter()
+            .map(|collision| {
+                (
+                    collision.primID0.min(collision.primID1),
+                    collision.primID0.max(collision.primID1),
+                )
+            })
+            .filter(|(prim_0, prim_1)| prim_0 != prim_1)
+            .filter(|(prim_0, prim_1)| !geometry.primitives_share_vertex(*prim_0, *prim_1))
+            .filter(|(prim_0, prim_1)| {
+                geometry
+                    .get_primitive(*prim_0)
+                    .intersects(&geometry.get_primitive(*prim_1))
+            })
+            .collect();
+
+        intersections.sort_unstable();
+        intersections.dedup();
+
+        Ok(intersections)
+    }
+
+    /// Get the primitive referred to by the [`PrimRef`].
+    pub(crate) fn get_primitive(&self, prim_ref: PrimRef) -> Primitive {
+        // the references come from committed scenes and geometries
+        // attached to a scene cannot be removed
+        self.geometries
+            .get(prim_ref.geometry_id.0)
+            .expect("geometry attached to a scene is available")
+            .get_primitive(prim_ref.primitive_index)
+    }
+}
+
+/// Collide function called by embree (possibly from multiple threads)
+/// with the pairs of user primitives whose bounds overlap.
+unsafe extern "C" fn collide_func(
+    user_ptr: *mut c_void,
+    collisions: *mut sys::RTCCollision,
+    num_collisions: std::os::raw::c_uint,
+) {
+    let all_collisions = &*(user_ptr as *const Mutex<Vec<sys::RTCCollision>>);
+    let collisions = std::slice::from_raw_parts(collisions, num_collisions as usize);
+
+    all_collisions
+        .lock()
+        .unwrap()
+        .extend(collisions.iter().map(|collision| sys::RTCCollision {
+            geomID0: collision.geomID0,
+            primID0: collision.primID0,
+            geomID1: collision.geomID1,
+            primID1: collision.primID1,
+        }));
+}
+
+/// Scene of user geometries mirroring the primitive bounds of a
+/// committed scene, since embree can only collide user geometries.
+///
+/// The bounds of the primitives of geometries with motion span all
+/// the time steps.
+///
+/// Every user geometry is attached with the [`GeometrySceneID`] of
+/// the geometry it mirrors, so the collisions map back to the
+/// original scene.
+pub(crate) struct CollisionScene {
+    scene: sys::RTCScene,
+}
+
+impl CollisionScene {
+    pub(crate) fn new<'a>(
+        device: &Device,
+        geometries: impl Iterator<Item = (GeometrySceneID, &'a Geometry)>,
+    ) -> Self {
+        let collision_scene = unsafe { sys::rtcNewScene(device.get_device()) };
+        assert!(
+            !collision_scene.is_null(),
+            "allocation of the collision scene failed"
+        );
+
+        geometries.for_each(|(geometry_scene_id, geometry)| {
+            let user_geometry = new_geometry(device, sys::RTCGeometryType_RTC_GEOMETRY_TYPE_USER)
+                .expect("allocation of the collision geometry failed");
+            unsafe {
+                let geometry_ptr = geometry as *const Geometry as *mut c_void;
+                sys::rtcSetGeometryUserPrimitiveCount(
+                    user_geometry,
+                    geometry.num_primitives().try_into().unwrap(),
+                );
+                sys::rtcSetGeometryUserData(user_geometry, geometry_ptr);
+                sys::rtcSetGeometryBoundsFunction(
+                    user_geometry,
+                    Some(primitive_bounds_func),
+                    geometry_ptr,
+                );
+                sys::rtcCommitGeometry(user_geometry);
+                sys::rtcAttachGeometryByID(collision_scene, user_geometry, geometry_scene_id.0);
+                // the scene holds a reference to the geometry
+                sys::rtcReleaseGeometry(user_geometry);
+            }
+        });
+
+        unsafe {
+            sys::rtcCommitScene(collision_scene);
+        }
+
+        Self {
+            scene: collision_scene,
+        }
+    }
+
+    /// Create the collision scene mirroring the enabled geometries
+    /// attached to the scene, disabled geometries never collide.
+    pub(crate) fn from_scene(embree: &Embree, scene: &SceneCommitted) -> Self {
+        Self::new(
+            &embree.device,
+            scene
+                .attached_geometries
+                .iter()
+                .map(|(geometry_scene_id, geometry_id)| {
+                    (
+                        *geometry_scene_id,
+                        embree
+                            .geometries
+                            .get(geometry_id.0)
+                            .expect("geometry of given id is not available"),
+                    )
+                })
+                .filter(|(_, geometry)| geometry.is_enabled()),
+        )
+    }
+
+    /// # Safety
+    ///
+    /// If not handled correctly, can lead to memory leaks or other
+    /// memory problems. It is always better to use the Rust API
+    /// instead of trying to get access to the FFI parts directly.
+    pub(crate) unsafe fn get_scene(&self) -> sys::RTCScene {
+        self.scene
+    }
+
+    /// Pairs of user primitives of the scenes whose bounds overlap.
+    fn collide(&self, other: &Self) -> Vec<sys::RTCCollision> {
+        let collisions: Mutex<Vec<sys::RTCCollision>> = Mutex::new(Vec::new());
+
+        unsafe {
+            sys::rtcCollide(
+                self.scene,
+                other.scene,
+                Some(collide_func),
+                &collisions as *const Mutex<Vec<sys::RTCCollision>> as *mut c_void,
+            );
+        }
+
+        collisions.into_inner().unwrap()
+    }
+}
+
+impl Drop for CollisionScene {
+    fn drop(&mut self) {
+        unsafe {
+            sys::rtcReleaseScene(self.scene);
+        }
+        self.scene = std::ptr::null_mut();
+    }
+}
+
+/// Bounds function of the user geometries of [`CollisionScene`],
+/// the geometry user pointer is the mirrored [`Geometry`].
+unsafe extern "C" fn primitive_bounds_func(args: *const sys::RTCBoundsFunctionArguments) {
+    let args = &*args;
+    let geometry = &*(args.geometryUserPtr as *const Geometry);
+    let bounds = geometry.primitive_bounds(args.primID);
+
+    let bounds_o = &mut *args.bounds_o;
+    bounds_o.lower_x = bounds.min.x;
+    bounds_o.lower_y = bounds.min.y;
+    bounds_o.lower_z = bounds.min.z;
+    bounds_o.upper_x = bounds.max.x;
+    bounds_o.upper_y = bounds.max.y;
+    bounds_o.upper_z = bounds.max.z;
+}
+
+/// A single primitive of a geometry in world space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Primitive {
+    Triangle([Vec3; 3]),
+    Quad([Vec3; 4]),
+    Sphere(Vec3, f32),
+}
+
+impl Primitive {
+    pub(crate) fn bounds(&self) -> Aabb {
+        match self {
+            Primitive::Triangle([v0, v1, v2]) => Aabb::empty().grow(*v0).grow(*v1).grow(*v2),
+            Primitive::Quad(quad) => quad
+                .iter()
+                .fold(Aabb::empty(), |bounds, vert| bounds.grow(*vert)),
+            Primitive::Sphere(center, radius) => Sphere::new(*center, *radius).bounds(),
+        }
+    }
+
+    /// Check if the primitives intersect.
+    ///
+    /// Triangles are tested by intersecting the edges of each
+    /// triangle with the other triangle, so coplanar triangles only
+    /// intersect if their edges cross. Quads are tested as their two
+    /// triangles.
+    pub(crate) fn intersects(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Primitive::Quad(quad), other) | (other, Primitive::Quad(quad)) => quad_triangles(quad)
+                .iter()
+                .any(|triangle| Primitive::Triangle(*triangle).intersects(other)),
+            (Primitive::Triangle(a), Primitive::Triangle(b)) => triangles_intersect(a, b),
+            (Primitive::Sphere(c0, r0), Primitive::Sphere(c1, r1)) => {
+                (*c0 - *c1).length() <= r0 + r1
+            }
+            (Primitive::Triangle([v0, v1, v2]), Primitive::Sphere(center, radius))
+            | (Primitive::Sphere(center, radius), Primitive::Triangle([v0, v1, v2])) => {
+                let (point, _) = closest_point_on_triangle(*center, *v0, *v1, *v2);
+                (point - *center).length() <= *radius
+            }
+        }
+    }
+}
+
+impl Geometry {
+    /// Number of primitives (triangles, spheres, etc.) of the
+    /// geometry.
+    pub(crate) fn num_primitives(&self) -> usize {
+        match self {
+            Geometry::Triangle(geometry) => geometry.get_triangles().len(),
+            Geometry::Quad(geometry) => geometry.get_quads().len(),
+            Geometry::Sphere(geometry) => geometry.get_spheres().len(),
+        }
+    }
+
+    /// Check if the primitives of the given indices share a vertex,
+    /// only triangles and quads share vertices.
+    pub(crate) fn primitives_share_vertex(&self, prim_0: u32, prim_1: u32) -> bool {
+        match self {
+            Geometry::Triangle(geometry) => {
+                let triangles = geometry.get_triangles();
+                let t0 = triangles[prim_0 as usize];
+                let t1 = triangles[prim_1 as usize];
+                [t0.i0, t0.i1, t0.i2]
+                    .iter()
+                    .any(|index| [t1.i0, t1.i1, t1.i2].contains(index))
+            }
+            Geometry::Quad(geometry) => {
+                let quads = geometry.get_quads();
+                let q0 = quads[prim_0 as usize];
+                let q1 = quads[prim_1 as usize];
+                [q0.i0, q0.i1, q0.i2, q0.i3]
+                    .iter()
+                    .any(|index| [q1.i0, q1.i1, q1.i2, q1.i3].contains(index))
+            }
+            Geometry::Sphere(_) => false,
+        }
+    }
+
+    /// Number of time steps of the geometry, geometries without
+    /// motion have a single time step.
+    pub(crate) fn num_time_steps(&self) -> u32 {
+        match self {
+            Geometry::Triangle(geometry) => geometry.get_num_time_steps(),
+            Geometry::Quad(_) | Geometry::Sphere(_) => 1,
+        }
+    }
+
+    /// Bounds of the primitive of the given index over all the time
+    /// steps.
+    pub(crate) fn primitive_bounds(&self, primitive_index: u32) -> Aabb {
+        (0..self.num_time_steps()).fold(Aabb::empty(), |bounds, time_step| {
+            bounds.union(
+                &self
+                    .get_primitive_at_time_step(primitive_index, time_step)
+                    .bounds(),
+            )
+        })
+    }
+
+    /// Get the primitive of the given index, at the first time step
+    /// for geometries with motion.
+    pub(crate) fn get_primitive(&self, primitive_index: u32) -> Primitive {
+        self.get_primitive_at_time_step(primitive_index, 0)
+    }
+
+    /// Get the primitive of the given index at the given time step.
+    pub(crate) fn get_primitive_at_time_step(
+        &self,
+        primitive_index: u32,
+        time_step: u32,
+    ) -> Primitive {
+        match self {
+            Geometry::Triangle(geometry) => {
+                let verts = geometry.get_verts_at_time_step(time_step);
+                let triangle = geometry.get_triangles()[primitive_index as usize];
+                Primitive::Triangle([
+                    verts[triangle.i0 as usize].pos,
+                    verts[triangle.i1 as usize].pos,
+                    verts[triangle.i2 as usize].pos,
+                ])
+            }
+            Geometry::Quad(geometry) => {
+                let verts = geometry.get_verts();
+                let quad = geometry.get_quads()[primitive_index as usize];
+                Primitive::Quad([
+                    verts[quad.i0 as usize].pos,
+                    verts[quad.i1 as usize].pos,
+                    verts[quad.i2 as usize].pos,
+                    verts[quad.i3 as usize].pos,
+                ])
+            }
+            Geometry::Sphere(geometry) => {
+                let sphere = geometry.get_spheres()[primitive_index as usize];
+                Primitive::Sphere(sphere.pos, sphere.radius)
+            }
+        }
+    }
+}
+
+/// Check if the triangles intersect, by checking if any edge of
+/// either triangle intersects the other triangle.
+pub(crate) fn triangles_intersect(a: &[Vec3; 3], b: &[Vec3; 3]) -> bool {
+    let edges_intersect = |t0: &[Vec3; 3], t1: &[Vec3; 3]| {
+        (0..3).any(|i| segment_intersects_triangle(t0[i], t0[(i + 1) % 3], t1))
+    };
+
+    edges_intersect(a, b) || edges_intersect(b, a)
+}
+
+/// Check if the segment `p0` to `p1` intersects the triangle.
+///
+/// Reference: Möller–Trumbore ray triangle intersection restricted
+/// to the segment.
+pub(crate) fn segment_intersects_triangle(p0: Vec3, p1: Vec3, triangle: &[Vec3; 3]) -> bool {
+    let [v0, v1, v2] = *triangle;
+    let direction = p1 - p0;
+    let e1 = v1 - v0;
+    let e2 = v2 - v0;
+
+    let h = direction.cross(&e2);
+    let det = e1.dot(&h);
+    // the determinant scales with the lengths of the segment and the
+    // edges, so the tolerance must too
+    if det.abs() <= f32::EPSILON * direction.length() * e1.length() * e2.length() {
+        // segment is parallel to the triangle
+        return false;
+    }
+    let inv_det = 1.0 / det;
+
+    let s = p0 - v0;
+    let u = inv_det * s.dot(&h);
+    if !(0.0..=1.0).contains(&u) {
+        return false;
+    }
+
+    let q = s.cross(&e1);
+    let v = inv_det * direction.dot(&q);
+    if v < 0.0 || u + v > 1.0 {
+        return false;
+    }
+
+    let t = inv_det * e2.dot(&q);
+    (0.0..=1.0).contains(&t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{triangles_intersect, Collision};
+    use crate::{Embree, Error, PrimRef, Triangle, Vec3, Vert};
+
+    #[test]
+    fn triangles_intersect_test() {
+        let a = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        ];
+        let b = [
+            Vec3::new(0.25, 0.25, -1.0),
+            Vec3::new(0.25, 0.25, 1.0),
+            Vec3::new(2.0, 2.0, 0.0),
+        ];
+        let c = [
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(1.0, 0.0, 1.0),
+            Vec3::new(0.0, 1.0, 1.0),
+        ];
+
+        assert!(triangles_intersect(&a, &b));
+        assert!(triangles_intersect(&b, &a));
+        assert!(!triangles_intersect(&a, &c));
+
+        // the determinant of small triangles is far below
+        // `f32::EPSILON`
+        let scale = |t: [Vec3; 3]| t.map(|v| v * 0.001);
+        assert!(triangles_intersect(&scale(a), &scale(b)));
+        assert!(triangles_intersect(&scale(b), &scale(a)));
+        assert!(!triangles_intersect(&scale(a), &scale(c)));
+    }
+
+    /// [`Embree::collide()`] must report overlapping primitives, the
+    /// exact narrow phase must discard the non intersecting ones
+    #[test]
+    fn collide_scenes() {
+        let mut embree = Embree::new();
+
+        let scene_a = embree.add_scene();
+        let triangle_a = embree.add_geometry_triangle(
+            &[
+                Vert::new(Vec3::new(0.0, 0.0, 0.0)),
+                Vert::new(Vec3::new(1.0, 0.0, 0.0)),
+                Vert::new(Vec3::new(0.0, 1.0, 0.0)),
+            ],
+            &[Triangle::new(0, 1, 2)],
+        );
+        embree.attach_geometry_to_scene(triangle_a, scene_a);
+        let scene_a = embree.commit_scene(scene_a);
+
+        let scene_b = embree.add_scene();
+        let triangle_b = embree.add_geometry_triangle(
+            &[
+                // intersects triangle_a
+                Vert::new(Vec3::new(0.25, 0.25, -1.0)),
+                Vert::new(Vec3::new(0.25, 0.25, 1.0)),
+                Vert::new(Vec3::new(2.0, 2.0, 0.0)),
+                // bounds overlap triangle_a but does not intersect
+                Vert::new(Vec3::new(0.9, 0.9, -1.0)),
+                Vert::new(Vec3::new(0.9, 0.9, 1.0)),
+                Vert::new(Vec3::new(2.0, 2.0, 0.0)),
+            ],
+            &[Triangle::new(0, 1, 2), Triangle::new(3, 4, 5)],
+        );
+        embree.attach_geometry_to_scene(triangle_b, scene_b);
+        let scene_b = embree.commit_scene(scene_b);
+
+        let collect = |exact| {
+            let mut collisions = Vec::new();
+            embree
+                .collide(scene_a, scene_b, exact, |collision| {
+                    collisions.push(collision)
+                })
+                .unwrap();
+            collisions.sort_unstable();
+            collisions
+        };
+
+        let collision = |primitive_index| Collision {
+            prim_a: PrimRef {
+                geometry_id: triangle_a,
+                primitive_index: 0,
+            },
+            prim_b: PrimRef {
+                geometry_id: triangle_b,
+                primitive_index,
+            },
+        };
+
+        assert_eq!(collect(false), vec![collision(0), collision(1)]);
+        assert_eq!(collect(true), vec![collision(0)]);
+
+        // disabled geometries must not collide
+        embree.set_geometry_enabled(triangle_b, false).unwrap();
+        let mut collisions = Vec::new();
+        embree
+            .collide(scene_a, scene_b, false, |collision| {
+                collisions.push(collision)
+            })
+            .unwrap();
+        assert!(collisions.is_empty());
+
+        let uncommitted_scene = embree.add_scene();
+        assert_eq!(
+            embree.collide(scene_a, uncommitted_scene, false, |_| {}),
+            Err(Error::SceneNotCommitted)
+        );
+    }
+
+    /// Only non adjacent intersecting triangles must be reported
+    #[test]
+    fn self_intersections() {
+        let mut embree = Embree::new();
+        let mesh = embree.add_geometry_triangle(
+            &[
+                Vert::new(Vec3::new(0.0, 0.0, 0.0)),
+                Vert::new(Vec3::new(1.0, 0.0, 0.0)),
+                Vert::new(Vec3::new(0.0, 1.0, 0.0)),
+                // adjacent to triangle 0
+                Vert::new(Vec3::new(1.0, 1.0, 0.5)),
+                // pierces triangle 0
+                Vert::new(Vec3::new(0.25, 0.25, -1.0)),
+                Vert::new(Vec3::new(0.25, 0.25, 1.0)),
+                Vert::new(Vec3::new(0.4, 0.4, 0.0)),
+            ],
+            &[
+                Triangle::new(0, 1, 2),
+                Triangle::new(1, 3, 2),
+                Triangle::new(4, 5, 6),
+            ],
+        );
+
+        assert_eq!(embree.self_intersections(mesh), Ok(vec![(0, 2)]));
+
+        embree.remove_geometry(mesh).unwrap();
+        assert_eq!(
+            embree.self_intersections(mesh),
+            Err(Error::GeometryNotFound)
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{sys, Device, Embree, Error};
+
+/// Version and capabilities of the embree library, see
+/// [`Embree::device_info()`]
+///
+/// Embree does not report the ISA it was compiled for through the
+/// device properties, so it is not part of the info.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceInfo {
+    pub version: Version,
+    pub native_ray4_supported: bool,
+    pub native_ray8_supported: bool,
+    pub native_ray16_supported: bool,
+    pub ray_stream_supported: bool,
+    pub ray_mask_supported: bool,
+    pub backface_culling_enabled: bool,
+    pub backface_culling_curves_enabled: bool,
+    pub filter_function_supported: bool,
+    pub ignore_invalid_rays_enabled: bool,
+    pub compact_polys_enabled: bool,
+    pub triangle_geometry_supported: bool,
+    pub quad_geometry_supported: bool,
+    pub subdivision_geometry_supported: bool,
+    pub curve_geometry_supported: bool,
+    pub user_geometry_supported: bool,
+    pub point_geometry_supported: bool,
+    pub tasking_system: TaskingSystem,
+    pub join_commit_supported: bool,
+    pub parallel_commit_supported: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Tasking system embree was compiled with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskingSystem {
+    Internal,
+    Tbb,
+    Ppl,
+    Unknown(isize),
+}
+
+/// Optional feature of embree that can be compiled out, see
+/// [`Embree::require_features()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Feature {
+    RayMask,
+    BackfaceCulling,
+    FilterFunction,
+    IgnoreInvalidRays,
+    RayStream,
+    TriangleGeometry,
+    UserGeometry,
+    PointGeometry,
+    JoinCommit,
+    ParallelCommit,
+}
+
+impl std::fmt::Display for Feature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Feature::RayMask => "ray mask",
+            Feature::BackfaceCulling => "backface culling",
+            Feature::FilterFunction => "filter function",
+            Feature::IgnoreInvalidRays => "ignore invalid rays",
+            Feature::RayStream => "ray stream",
+            Feature::TriangleGeometry => "triangle geometry",
+            Feature::UserGeometry => "user geometry",
+            Feature::PointGeometry => "point geometry",
+            Feature::JoinCommit => "join commit",
+            Feature::ParallelCommit => "parallel commit",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl DeviceInfo {
+    pub(crate) fn new(device: &Device) -> Self {
+        let property =
+            |property| unsafe { sys::rtcGetDeviceProperty(device.get_device(), property) };
+        let flag = |flag| property(flag) != 0;
+
+        Self {
+            version: Version {
+                major: property(sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_VERSION_MAJOR)
+                    .try_into()
+                    .unwrap(),
+                minor: property(sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_VERSION_MINOR)
+                    .try_into()
+                    .unwrap(),
+                patch: property(sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_VERSION_PATCH)
+                    .try_into()
+                    .unwrap(),
+            },
+            native_ray4_supported: flag(
+                sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_NATIVE_RAY4_SUPPORTED,
+            ),
+            native_ray8_supported: flag(
+                sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_NATIVE_RAY8_SUPPORTED,
+            ),
+            native_ray16_supported: flag(
+                sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_NATIVE_RAY16_SUPPORTED,
+            ),
+            ray_stream_supported: flag(
+                sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_RAY_STREAM_SUPPORTED,
+            ),
+            ray_mask_supported: flag(sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_RAY_MASK_SUPPORTED),
+            backface_culling_enabled: flag(
+                sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_BACKFACE_CULLING_ENABLED,
+            ),
+            backface_culling_curves_enabled: flag(
+                sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_BACKFACE_CULLING_CURVES_ENABLED,
+            ),
+            filter_function_supported: flag(
+                sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_FILTER_FUNCTION_SUPPORTED,
+            ),
+            ignore_invalid_rays_enabled: flag(
+                sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_IGNORE_INVALID_RAYS_ENABLED,
+            ),
+            compact_polys_enabled: flag(
+                sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_COMPACT_POLYS_ENABLED,
+            ),
+            triangle_geometry_supported: flag(
+                sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_TRIANGLE_GEOMETRY_SUPPORTED,
+            ),
+            quad_geometry_supported: flag(
+                sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_QUAD_GEOMETRY_SUPPORTED,
+            ),
+            subdivision_geometry_supported: flag(
+                sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_SUBDIVISION_GEOMETRY_SUPPORTED,
+            ),
+            curve_geometry_supported: flag(
+                sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_CURVE_GEOMETRY_SUPPORTED,
+            ),
+            user_geometry_supported: flag(
+                sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_USER_GEOMETRY_SUPPORTED,
+            ),
+            point_geometry_supported: flag(
+                sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_POINT_GEOMETRY_SUPPORTED,
+            ),
+            tasking_system: match property(
+                sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_TASKING_SYSTEM,
+            ) {
+                0 => TaskingSystem::Internal,
+                1 => TaskingSystem::Tbb,
+                2 => TaskingSystem::Ppl,
+                other => TaskingSystem::Unknown(other),
+            },
+            join_commit_supported: flag(
+                sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_JOIN_COMMIT_SUPPORTED,
+            ),
+            parallel_commit_supported: flag(
+                sys::RTCDeviceProperty_RTC_DEVICE_PROPERTY_PARALLEL_COMMIT_SUPPORTED,
+            ),
+        }
+    }
+
+    /// Check if embree was compiled with the feature.
+    pub fn supports(&self, feature: Feature) -> bool {
+        match feature {
+            Feature::RayMask => self.ray_mask_supported,
+            Feature::BackfaceCulling => self.backface_culling_enabled,
+            Feature::FilterFunction => self.filter_function_supported,
+            Feature::IgnoreInvalidRays => self.ignore_invalid_rays_enabled,
+            Feature::RayStream => self.ray_stream_supported,
+            Feature::TriangleGeometry => self.triangle_geometry_supported,
+            Feature::UserGeometry => self.user_geometry_supported,
+            Feature::PointGeometry => self.point_geometry_supported,
+            Feature::JoinCommit => self.join_commit_supported,
+            Feature::ParallelCommit => self.parallel_commit_supported,
+        }
+    }
+}
+
+impl Embree {
+    /// Version and capabilities of the linked embree library.
+    pub fn device_info(&self) -> DeviceInfo {
+        DeviceInfo::new(&self.device)
+    }
+
+    /// Check that embree was compiled with all the given features,
+    /// returns [`Error::FeatureNotSupported`] with the first missing
+    /// feature otherwise.
+    ///
+    /// Useful to fail early, before any scene is built that relies
+    /// on the features.
+    pub fn require_features(&self, features: &[Feature]) -> Result<(), Error> {
+        let info = self.device_info();
+        features
+            .iter()
+            .find(|feature| !info.supports(**feature))
+            .map_or(Ok(()), |feature| Err(Error::FeatureNotSupported(*feature)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Feature;
+    use crate::{Embree, Error};
+
+    /// Embree compiled from source is built with ray masks (see
+    /// `build.rs`), a prebuilt archive or an installed embree might
+    /// not be
+    #[test]
+    fn device_info() {
+        let embree = Embree::new();
+        let info = embree.device_info();
+
+        assert_eq!(
+            info.version.major,
+            if cfg!(feature = "embree4") { 4 } else { 3 }
+        );
+        if cfg!(embree_ray_mask) {
+            assert!(info.ray_mask_supported);
+        }
+        assert!(info.triangle_geometry_supported);
+        assert!(info.user_geometry_supported);
+        assert_eq!(
+            embree.require_features(&[Feature::RayMask, Feature::UserGeometry]),
+            if info.ray_mask_supported {
+                Ok(())
+            } else {
+                Err(Error::FeatureNotSupported(Feature::RayMask))
+            }
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{CommitHandle, Embree, Error, GeometryID, Scene, SceneID};
+
+/// Pair of scenes sharing geometries, the front scene is committed
+/// and can be queried while the back scene is changed and committed
+///
+/// Pass [`Self::front()`] to the query functions of [`Embree`] and
+/// make the changes for the next frame to [`Self::back()`], then
+/// [`Self::swap()`] publishes the back scene as the new front scene.
+#[derive(Debug)]
+pub struct SceneDoubleBuffer {
+    front: SceneID,
+    back: BackScene,
+}
+
+#[derive(Debug)]
+enum BackScene {
+    Uncommitted(SceneID),
+    Committing(CommitHandle),
+}
+
+impl SceneDoubleBuffer {
+    /// Create a double buffer with empty front and back scenes.
+    pub fn new(embree: &mut Embree) -> Self {
+        let front = embree.add_scene();
+        let front = embree.commit_scene(front);
+        let back = embree.add_scene();
+        Self {
+            front,
+            back: BackScene::Uncommitted(back),
+        }
+    }
+
+    /// Id of the committed scene that the queries should target.
+    pub fn front(&self) -> SceneID {
+        self.front
+    }
+
+    /// Id of the uncommitted scene that the changes for the next
+    /// swap are made to.
+    ///
+    /// The back scene starts with the same geometries attached as
+    /// the front scene.
+    ///
+    /// # Panics
+    ///
+    /// If the back scene is being committed, see
+    /// [`Self::commit_back()`].
+    pub fn back(&self) -> SceneID {
+        match &self.back {
+            BackScene::Uncommitted(id) => *id,
+            BackScene::Committing(_) => unreachable!("back scene is being committed"),
+        }
+    }
+
+    /// Start committing the back scene on a background thread, so
+    /// that [`Self::swap()`] does not have to wait as long.
+    pub fn commit_back(&mut self, embree: &mut Embree) {
+        if let BackScene::Uncommitted(id) = self.back {
+            self.back = BackScene::Committing(embree.commit_scene_async(id));
+        }
+    }
+
+    /// Check if [`Self::swap()`] can happen without waiting for the
+    /// back scene to be built.
+    pub fn is_back_ready(&self) -> bool {
+        match &self.back {
+            BackScene::Uncommitted(_) => false,
+            BackScene::Committing(handle) => handle.is_finished(),
+        }
+    }
+
+    /// Commit the back scene (or wait for the commit started by
+    /// [`Self::commit_back()`] to finish) and make it the front
+    /// scene.
+    ///
+    /// The previous front scene is removed and a new back scene with
+    /// the geometries of the new front scene attached is created.
+    ///
+    /// If embree fails to build the back scene, the error is returned
+    /// and nothing is swapped, the back scene stays uncommitted.
+    pub fn swap(&mut self, embree: &mut Embree) -> Result<(), Error> {
+        self.commit_back(embree);
+
+        let back = std::mem::replace(&mut self.back, BackScene::Uncommitted(self.front));
+        let new_front = match back {
+            BackScene::Committing(handle) => {
+                let back = handle.id;
+                embree
+                    .finish_commit(handle)
+                    .inspect_err(|_| self.back = BackScene::Uncommitted(back))?
+            }
+            BackScene::Uncommitted(_) => unreachable!(),
+        };
+
+        embree
+            .remove_scene(self.front)
+            .expect("front scene is committed");
+        self.front = new_front;
+
+        let new_back = embree.add_scene();
+        attached_geometries(embree, self.front)
+            .into_iter()
+            .for_each(|geometry_id| embree.attach_geometry_to_scene(geometry_id, new_back));
+        self.back = BackScene::Uncommitted(new_back);
+
+        Ok(())
+    }
+
+    /// Remove both the scenes of the double buffer, the geometries
+    /// are not removed.
+    pub fn remove(self, embree: &mut Embree) {
+        embree
+            .remove_scene(self.front)
+            .expect("front scene is committed");
+        let back = match self.back {
+            BackScene::Uncommitted(id) => id,
+            BackScene::Committing(handle) => {
+                let back = handle.id;
+                // the scene stays under the same id if the commit fails
+                embree.finish_commit(handle).unwrap_or(back)
+            }
+        };
+        embree
+            .remove_scene(back)
+            .expect("back scene is not being committed anymore");
+    }
+}
+
+/// Geometries attached to the scene of the given id, in the order
+/// they were attached.
+fn attached_geometries(embree: &Embree, scene_id: SceneID) -> Vec<GeometryID> {
+    // only called with the front scene, which the double buffer owns
+    let scene: &Scene = embree
+        .scenes
+        .get(scene_id.0)
+        .expect("scene of the double buffer is available");
+
+    let mut geometries: Vec<_> = scene.get_attached_geometries().iter().collect();
+    geometries.sort_unstable_by_key(|(geometry_scene_id, _)| **geometry_scene_id);
+    geometries
+        .into_iter()
+        .map(|(_, geometry_id)| *geometry_id)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SceneDoubleBuffer;
+    use crate::{Embree, Ray, Sphere, Vec3};
+
+    /// Changes to the back scene must only be visible through the
+    /// front scene after a swap
+    #[test]
+    fn swap() {
+        let mut embree = Embree::new();
+        let ray = |x: f32| {
+            Ray::new(
+                Vec3::new(x, 0.0, 5.0),
+                0.0,
+                f32::INFINITY,
+                Vec3::new(0.0, 0.0, -1.0),
+                0.0,
+            )
+        };
+
+        let mut buffer = SceneDoubleBuffer::new(&mut embree);
+        assert!(embree.closest_hit(buffer.front(), ray(0.0)).is_none());
+
+        let sphere_1_id = embree.add_geometry_sphere(&[Sphere::new(Vec3::new(0.0, 0.0, 0.0), 1.0)]);
+        embree.attach_geometry_to_scene(sphere_1_id, buffer.back());
+        assert!(embree.closest_hit(buffer.front(), ray(0.0)).is_none());
+
+        buffer.swap(&mut embree).unwrap();
+        assert_eq!(
+            embree
+                .closest_hit(buffer.front(), ray(0.0))
+                .unwrap()
+                .geometry_id,
+            sphere_1_id
+        );
+
+        let sphere_2_id = embree.add_geometry_sphere(&[Sphere::new(Vec3::new(5.0, 0.0, 0.0), 1.0)]);
+        embree.attach_geometry_to_scene(sphere_2_id, buffer.back());
+        buffer.commit_back(&mut embree);
+        assert!(embree.closest_hit(buffer.front(), ray(5.0)).is_none());
+
+        buffer.swap(&mut embree).unwrap();
+        assert_eq!(
+            embree
+                .closest_hit(buffer.front(), ray(0.0))
+                .unwrap()
+                .geometry_id,
+            sphere_1_id
+        );
+        assert_eq!(
+            embree
+                .closest_hit(buffer.front(), ray(5.0))
+                .unwrap()
+                .geometry_id,
+            sphere_2_id
+        );
+
+        buffer.remove(&mut embree);
+        assert_eq!(embree.remove_geometry(sphere_1_id), Ok(()));
+        assert_eq!(embree.remove_geometry(sphere_2_id), Ok(()));
+    }
+}
@@ -1,41 +1,65 @@
 use std::collections::HashMap;
+use std::os::raw::c_void;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
 
 use generational_arena::{Arena, Index};
-
+use memory::MemoryMonitor;
+
+pub mod bvh;
+pub mod ccd;
+pub mod collide;
+pub mod device_info;
+pub mod double_buffer;
+pub mod memory;
+pub mod point_query;
 pub mod sys;
 
+pub use bvh::{BuildPrimitive, BuildQuality, BvhBuildConfig};
+pub use ccd::TimeOfImpact;
+pub use collide::Collision;
+pub use device_info::{DeviceInfo, Feature, TaskingSystem, Version};
+pub use double_buffer::SceneDoubleBuffer;
+pub use memory::MemoryUsage;
+pub use point_query::{ClosestPoint, PrimRef};
+
 pub const INVALID_GEOMETRY_ID: u32 = sys::RTC_INVALID_GEOMETRY_ID;
 
 #[derive(Debug)]
 pub struct Embree {
-    device: Device,
     scenes: Arena<Scene>,
     geometries: Arena<Geometry>,
-    /// Map from GeometrySceneID to GeometryID, useful for when embree
-    /// gives the GeometrySceneID but the user must be provided with
-    /// the GeometryID.
-    geometry_id_map: HashMap<GeometrySceneID, GeometryID>,
+    /// Dropped last, embree calls the memory monitor of the device
+    /// when the scenes and geometries are released
+    device: Device,
 }
 
 impl Embree {
     pub fn new() -> Self {
         Self {
-            device: Device::new(),
             scenes: Arena::new(),
             geometries: Arena::new(),
-            geometry_id_map: HashMap::new(),
+            device: Device::new(),
         }
     }
 
+    /// # Panics
+    ///
+    /// If embree fails to allocate the scene, see
+    /// [`Self::try_add_scene()`].
     pub fn add_scene(&mut self) -> SceneID {
-        self.add_scene_uncommitted()
+        self.try_add_scene()
+            .expect("allocation of the scene failed")
     }
 
-    fn add_scene_uncommitted(&mut self) -> SceneID {
-        SceneID(
-            self.scenes
-                .insert(Scene::Uncommitted(SceneUncommitted::new(&self.device))),
-        )
+    /// Add a scene like [`Self::add_scene()`], returns
+    /// [`Error::MemoryLimitExceeded`] if embree fails to allocate the
+    /// scene.
+    pub fn try_add_scene(&mut self) -> Result<SceneID, Error> {
+        Ok(SceneID(self.scenes.insert(Scene::Uncommitted(
+            SceneUncommitted::new(&self.device)?,
+        ))))
     }
 
     fn add_scene_committed(&mut self, scene: SceneCommitted) -> SceneID {
@@ -51,65 +75,547 @@ impl Embree {
     //     self.scenes.get_mut(id.0)
     // }
 
+    /// # Panics
+    ///
+    /// If embree fails to allocate the geometry, see
+    /// [`Self::try_add_geometry_triangle()`].
     pub fn add_geometry_triangle(&mut self, verts: &[Vert], indices: &[Triangle]) -> GeometryID {
-        GeometryID(
-            self.geometries
-                .insert(Geometry::Triangle(GeometryTriangle::new(
-                    &self.device,
-                    verts,
-                    indices,
-                ))),
-        )
+        self.try_add_geometry_triangle(verts, indices)
+            .expect("allocation of the geometry failed")
+    }
+
+    /// Add a triangle geometry like [`Self::add_geometry_triangle()`],
+    /// returns [`Error::MemoryLimitExceeded`] if embree fails to
+    /// allocate the geometry or its buffers.
+    pub fn try_add_geometry_triangle(
+        &mut self,
+        verts: &[Vert],
+        indices: &[Triangle],
+    ) -> Result<GeometryID, Error> {
+        Ok(GeometryID(self.geometries.insert(Geometry::Triangle(
+            GeometryTriangle::new(&self.device, verts, indices)?,
+        ))))
+    }
+
+    /// Add a triangle geometry with motion, `time_step_verts` has the
+    /// vertex positions of each time step. The time steps are evenly
+    /// spread over the time range `[0, 1]` (the ray time) and the
+    /// positions are linearly interpolated in between.
+    ///
+    /// # Panics
+    ///
+    /// If embree fails to allocate the geometry, see
+    /// [`Self::try_add_geometry_triangle_motion()`].
+    pub fn add_geometry_triangle_motion(
+        &mut self,
+        time_step_verts: &[&[Vert]],
+        indices: &[Triangle],
+    ) -> GeometryID {
+        self.try_add_geometry_triangle_motion(time_step_verts, indices)
+            .expect("allocation of the geometry failed")
     }
 
+    /// Add a triangle geometry with motion like
+    /// [`Self::add_geometry_triangle_motion()`], returns
+    /// [`Error::MemoryLimitExceeded`] if embree fails to allocate the
+    /// geometry or its buffers.
+    pub fn try_add_geometry_triangle_motion(
+        &mut self,
+        time_step_verts: &[&[Vert]],
+        indices: &[Triangle],
+    ) -> Result<GeometryID, Error> {
+        Ok(GeometryID(self.geometries.insert(Geometry::Triangle(
+            GeometryTriangle::new_motion(&self.device, time_step_verts, indices)?,
+        ))))
+    }
+
+    /// # Panics
+    ///
+    /// If embree fails to allocate the geometry, see
+    /// [`Self::try_add_geometry_quad()`].
+    pub fn add_geometry_quad(&mut self, verts: &[Vert], indices: &[Quad]) -> GeometryID {
+        self.try_add_geometry_quad(verts, indices)
+            .expect("allocation of the geometry failed")
+    }
+
+    /// Add a quad geometry like [`Self::add_geometry_quad()`],
+    /// returns [`Error::MemoryLimitExceeded`] if embree fails to
+    /// allocate the geometry or its buffers.
+    pub fn try_add_geometry_quad(
+        &mut self,
+        verts: &[Vert],
+        indices: &[Quad],
+    ) -> Result<GeometryID, Error> {
+        Ok(GeometryID(self.geometries.insert(Geometry::Quad(
+            GeometryQuad::new(&self.device, verts, indices)?,
+        ))))
+    }
+
+    /// # Panics
+    ///
+    /// If embree fails to allocate the geometry, see
+    /// [`Self::try_add_geometry_sphere()`].
     pub fn add_geometry_sphere(&mut self, spheres: &[Sphere]) -> GeometryID {
-        GeometryID(
-            self.geometries
-                .insert(Geometry::Sphere(GeometrySphere::new(&self.device, spheres))),
-        )
+        self.try_add_geometry_sphere(spheres)
+            .expect("allocation of the geometry failed")
+    }
+
+    /// Add a sphere geometry like [`Self::add_geometry_sphere()`],
+    /// returns [`Error::MemoryLimitExceeded`] if embree fails to
+    /// allocate the geometry or its buffer.
+    pub fn try_add_geometry_sphere(&mut self, spheres: &[Sphere]) -> Result<GeometryID, Error> {
+        Ok(GeometryID(self.geometries.insert(Geometry::Sphere(
+            GeometrySphere::new(&self.device, spheres)?,
+        ))))
     }
 
     /// commits the scene of the given id and returns the new id of
     /// the scene
+    ///
+    /// # Panics
+    ///
+    /// If the commit fails, see [`Self::try_commit_scene()`].
     #[must_use = "the scene id will change, capture the new one"]
     pub fn commit_scene(&mut self, id: SceneID) -> SceneID {
+        self.try_commit_scene(id)
+            .expect("commit of the scene failed")
+    }
+
+    /// Commit the scene of the given id like
+    /// [`Self::commit_scene()`], returns
+    /// [`Error::MemoryLimitExceeded`] if the commit needs more memory
+    /// than allowed by [`Self::set_memory_limit()`]. The scene stays
+    /// uncommitted under the same id in that case.
+    pub fn try_commit_scene(&mut self, id: SceneID) -> Result<SceneID, Error> {
+        self.commit_scene_with_progress(id, |_| true)
+    }
+
+    /// Commit the scene of the given id like
+    /// [`Self::commit_scene()`], `progress` is called with the
+    /// fraction of the build done so far (in `[0, 1]`) and returns
+    /// whether the build should continue.
+    ///
+    /// If `progress` cancels the build, [`Error::CommitCancelled`] is
+    /// returned and the scene stays uncommitted under the same id,
+    /// so it can be committed again later. [`Error::SceneNotFound`]
+    /// is returned if there is no scene of the given id and
+    /// [`Error::SceneCommitting`] if it is being committed by
+    /// [`Self::commit_scene_async()`].
+    pub fn commit_scene_with_progress(
+        &mut self,
+        id: SceneID,
+        progress: impl FnMut(f64) -> bool + Send,
+    ) -> Result<SceneID, Error> {
+        self.collect_abandoned_commits();
+
+        let memory_start = self.commit_memory_start();
+        match self.scenes.get_mut(id.0).ok_or(Error::SceneNotFound)? {
+            Scene::Uncommitted(scene) => {
+                let result = scene.build_with_progress(progress);
+                // also clears the error of the device
+                let error = self.device.get_error();
+                result?;
+                Device::error_to_result(error)?;
+            }
+            Scene::Committed(_) => {}
+            Scene::Committing(_) => return Err(Error::SceneCommitting),
+        }
+
+        let scene = match self.scenes.remove(id.0).unwrap() {
+            Scene::Uncommitted(scene) => {
+                let mut scene = scene.into_committed();
+                scene.memory_usage = self.commit_memory_growth(memory_start);
+                scene
+            }
+            Scene::Committed(scene) => scene,
+            Scene::Committing(_) => unreachable!("committing scenes are returned above"),
+        };
+
+        Ok(self.add_scene_committed(scene))
+    }
+
+    /// Start a commit of the scene of the given id that multiple
+    /// threads can take part in, see [`JoinCommit`].
+    pub fn join_commit_scene(&mut self, id: SceneID) -> JoinCommit<'_> {
+        self.collect_abandoned_commits();
+
+        // TODO: propagate the error to the user
+        let scene = match self
+            .scenes
+            .get(id.0)
+            .expect("scene of given id is not available")
+        {
+            Scene::Uncommitted(scene) => Some(unsafe { scene.get_scene() }),
+            Scene::Committed(_) => None,
+            Scene::Committing(_) => unreachable!("scene is being committed already"),
+        };
+
+        JoinCommit {
+            join_supported: self.device_info().join_commit_supported,
+            memory_start: self.commit_memory_start(),
+            embree: self,
+            id,
+            scene,
+            joined: AtomicBool::new(false),
+            error: AtomicU32::new(sys::RTCError_RTC_ERROR_NONE),
+        }
+    }
+
+    /// Start committing the scene of the given id on a background
+    /// thread.
+    ///
+    /// Other scenes, such as the previously committed version of
+    /// this scene, can still be queried while the commit runs. Once
+    /// [`CommitHandle::is_finished()`], [`Self::finish_commit()`]
+    /// gives the new id of the scene without blocking, so the caller
+    /// can switch over to it at once.
+    ///
+    /// Until the commit is finished, the scene cannot be queried or
+    /// changed and the geometries attached to it cannot be changed.
+    /// If embree fails to build the scene, [`Self::finish_commit()`]
+    /// returns the error.
+    #[must_use = "the new id of the scene is given by `Embree::finish_commit()`"]
+    pub fn commit_scene_async(&mut self, id: SceneID) -> CommitHandle {
+        self.collect_abandoned_commits();
+
         // TODO: propagate the error to the user
         let scene = self
             .scenes
-            .remove(id.0)
+            .get_mut(id.0)
             .expect("scene of given id is not available");
 
-        let scene = match scene {
-            Scene::Uncommitted(scene) => scene.commit(),
-            Scene::Committed(scene) => scene,
+        let result = Arc::new(Mutex::new(None));
+        let committing = Scene::Committing(SceneCommitting {
+            attached_geometries: scene.get_attached_geometries().clone(),
+            result: result.clone(),
+        });
+
+        let scene = match std::mem::replace(scene, committing) {
+            Scene::Committing(_) => unreachable!("scene is being committed already"),
+            scene => scene,
         };
 
-        self.add_scene_committed(scene)
+        let thread_result = result.clone();
+        // embree calls the memory monitor on the commit thread, which
+        // may still run after `Embree` is dropped
+        let memory_monitor = self.device.memory_monitor.clone();
+        let thread = std::thread::spawn(move || {
+            // dropped in reverse order, the result is released here if
+            // `Embree` was dropped meanwhile, which calls the monitor
+            let _memory_monitor = memory_monitor;
+            let thread_result = thread_result;
+
+            let result = match scene {
+                Scene::Uncommitted(scene) => scene.commit(),
+                Scene::Committed(scene) => Ok(scene),
+                Scene::Committing(_) => unreachable!(),
+            };
+            *thread_result.lock().unwrap() = Some(result);
+        });
+
+        CommitHandle { id, thread, result }
+    }
+
+    /// Wait for the commit to finish and return the new id of the
+    /// scene.
+    ///
+    /// If embree failed to build the scene,
+    /// [`Error::MemoryLimitExceeded`] or [`Error::CommitFailed`] is
+    /// returned and the scene stays uncommitted under the same id.
+    pub fn finish_commit(&mut self, handle: CommitHandle) -> Result<SceneID, Error> {
+        handle
+            .thread
+            .join()
+            .expect("commit thread of the scene panicked");
+        let result = handle
+            .result
+            .lock()
+            .unwrap()
+            .take()
+            .expect("commit thread gives the scene back");
+
+        // the scene cannot be removed during the commit and is only
+        // collected by `Self::collect_abandoned_commits()` once the
+        // handle is dropped
+        let scene = self
+            .scenes
+            .get_mut(handle.id.0)
+            .expect("scene of the commit handle is being committed");
+
+        match result {
+            Ok(committed) => {
+                self.scenes.remove(handle.id.0);
+                Ok(self.add_scene_committed(committed))
+            }
+            Err((uncommitted, error)) => {
+                *scene = Scene::Uncommitted(uncommitted);
+                Err(error)
+            }
+        }
+    }
+
+    /// Take back the scenes of the commits whose [`CommitHandle`] was
+    /// dropped without [`Self::finish_commit()`], once their commit
+    /// thread is done. The scenes keep their id.
+    fn collect_abandoned_commits(&mut self) {
+        self.scenes.iter_mut().for_each(|(_, scene)| {
+            let Scene::Committing(committing) = scene else {
+                return;
+            };
+            // the thread and the handle hold the result too until
+            // they are done
+            if Arc::strong_count(&committing.result) != 1 {
+                return;
+            }
+
+            let result = committing.result.lock().unwrap().take();
+            match result {
+                Some(Ok(committed)) => *scene = Scene::Committed(committed),
+                // the error cannot be reported without the handle
+                Some(Err((uncommitted, _))) => *scene = Scene::Uncommitted(uncommitted),
+                // the commit thread panicked, the scene is lost
+                None => {}
+            }
+        });
     }
 
     pub fn attach_geometry_to_scene(&mut self, geometry_id: GeometryID, scene_id: SceneID) {
+        self.collect_abandoned_commits();
+
         // TODO: propagate the error to the user
-        let geometry_scene_id = match self
+        let scene = self
             .scenes
             .get_mut(scene_id.0)
-            .expect("scene of given id is not available")
-        {
-            Scene::Committed(_) => unreachable!("scene is committed already"),
-            Scene::Uncommitted(scene) => scene.attach_geometry(
-                self.geometries
-                    .get(geometry_id.0)
-                    .expect("geometry of given id is not available"),
-            ),
-        };
+            .expect("scene of given id is not available");
 
-        let old_id = self.geometry_id_map.insert(geometry_scene_id, geometry_id);
         assert!(
-            old_id.is_none(),
+            !scene.is_geometry_attached(geometry_id),
             "geometry might have been attached already"
-        )
+        );
+
+        match scene {
+            Scene::Committed(_) => unreachable!("scene is committed already"),
+            Scene::Committing(_) => unreachable!("scene is being committed"),
+            Scene::Uncommitted(scene) => {
+                scene.attach_geometry(
+                    geometry_id,
+                    self.geometries
+                        .get(geometry_id.0)
+                        .expect("geometry of given id is not available"),
+                );
+            }
+        }
+    }
+
+    /// Detach the geometry of the given id from the scene of the
+    /// given id.
+    ///
+    /// The scene must not be committed yet, similar to
+    /// [`Self::attach_geometry_to_scene()`],
+    /// [`Error::SceneCommitted`] is returned otherwise and
+    /// [`Error::SceneCommitting`] if the scene is being committed by
+    /// [`Self::commit_scene_async()`]. [`Error::GeometryNotAttached`]
+    /// is returned if the geometry is not attached to the scene.
+    pub fn detach_geometry_from_scene(
+        &mut self,
+        geometry_id: GeometryID,
+        scene_id: SceneID,
+    ) -> Result<(), Error> {
+        self.collect_abandoned_commits();
+
+        match self.scenes.get_mut(scene_id.0) {
+            None => Err(Error::SceneNotFound),
+            Some(Scene::Committed(_)) => Err(Error::SceneCommitted),
+            Some(Scene::Committing(_)) => Err(Error::SceneCommitting),
+            Some(Scene::Uncommitted(scene)) => scene
+                .detach_geometry(geometry_id)
+                .map(|_| ())
+                .ok_or(Error::GeometryNotAttached),
+        }
+    }
+
+    /// Enable or disable the geometry of the given id.
+    ///
+    /// Disabled geometries are skipped by all queries but stay
+    /// attached to their scenes. The committed scenes that the
+    /// geometry is attached to are committed again so the change has
+    /// effect immediately, uncommitted scenes pick up the change when
+    /// they are committed.
+    ///
+    /// If embree fails to commit one of the scenes again, the error
+    /// is returned and the scene becomes uncommitted under the same
+    /// id, it must be committed again before it can be queried.
+    /// [`Error::SceneCommitting`] is returned if the geometry is
+    /// attached to a scene that is being committed by
+    /// [`Self::commit_scene_async()`] and [`Error::GeometryNotFound`]
+    /// if there is no geometry of the given id.
+    pub fn set_geometry_enabled(
+        &mut self,
+        geometry_id: GeometryID,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        self.check_geometry_not_committing(geometry_id)?;
+
+        self.geometries
+            .get_mut(geometry_id.0)
+            .ok_or(Error::GeometryNotFound)?
+            .set_enabled(enabled);
+
+        self.recommit_scenes_with_geometry(geometry_id)
+    }
+
+    /// Set the mask of the geometry of the given id.
+    ///
+    /// A ray only hits the geometry if the bitwise AND of the ray
+    /// mask (see [`Ray::with_mask()`]) and the geometry mask is not
+    /// zero. The committed scenes that the geometry is attached to
+    /// are committed again so the change has effect immediately, see
+    /// [`Self::set_geometry_enabled()`] for when that fails.
+    ///
+    /// Embree must be compiled with `EMBREE_RAY_MASK` enabled for
+    /// the mask to be respected, [`Error::FeatureNotSupported`] is
+    /// returned otherwise and the mask is left unchanged. The bundled
+    /// embree is compiled with it unless a prebuilt archive is given
+    /// through `EMBREE_RUST_PREBUILT_ARCHIVE`, an installed embree
+    /// (the `system` feature) might not be.
+    pub fn set_geometry_mask(&mut self, geometry_id: GeometryID, mask: u32) -> Result<(), Error> {
+        self.require_features(&[Feature::RayMask])?;
+        self.check_geometry_not_committing(geometry_id)?;
+
+        self.geometries
+            .get(geometry_id.0)
+            .ok_or(Error::GeometryNotFound)?
+            .set_mask(mask);
+
+        self.recommit_scenes_with_geometry(geometry_id)
+    }
+
+    /// Committed scene of the given id, returns
+    /// [`Error::SceneNotCommitted`] or [`Error::SceneCommitting`] if
+    /// the scene cannot be queried yet.
+    pub(crate) fn get_committed_scene(&self, id: SceneID) -> Result<&SceneCommitted, Error> {
+        match self.scenes.get(id.0).ok_or(Error::SceneNotFound)? {
+            Scene::Uncommitted(_) => Err(Error::SceneNotCommitted),
+            Scene::Committing(_) => Err(Error::SceneCommitting),
+            Scene::Committed(scene) => Ok(scene),
+        }
+    }
+
+    /// Geometries attached to a scene that is being committed on a
+    /// background thread must not change until the commit finishes,
+    /// returns [`Error::SceneCommitting`] otherwise.
+    fn check_geometry_not_committing(&mut self, geometry_id: GeometryID) -> Result<(), Error> {
+        self.collect_abandoned_commits();
+
+        if self.scenes.iter().any(|(_, scene)| {
+            matches!(scene, Scene::Committing(_)) && scene.is_geometry_attached(geometry_id)
+        }) {
+            return Err(Error::SceneCommitting);
+        }
+
+        Ok(())
+    }
+
+    /// Commit again all the committed scenes that the geometry of
+    /// the given id is attached to, returns the first error.
+    fn recommit_scenes_with_geometry(&mut self, geometry_id: GeometryID) -> Result<(), Error> {
+        self.scenes
+            .iter_mut()
+            .filter(|(_, scene)| scene.is_geometry_attached(geometry_id))
+            // commit all the scenes even if one of them fails
+            .filter_map(|(_, scene)| scene.recommit().err())
+            .collect::<Vec<_>>()
+            .first()
+            .map_or(Ok(()), |error| Err(*error))
+    }
+
+    /// Remove the geometry of the given id.
+    ///
+    /// The geometry must be detached from all the scenes it was
+    /// attached to (or those scenes must be removed) before the
+    /// geometry can be removed, [`Error::GeometryStillAttached`] is
+    /// returned otherwise.
+    pub fn remove_geometry(&mut self, geometry_id: GeometryID) -> Result<(), Error> {
+        self.collect_abandoned_commits();
+
+        if !self.geometries.contains(geometry_id.0) {
+            return Err(Error::GeometryNotFound);
+        }
+
+        if self
+            .scenes
+            .iter()
+            .any(|(_, scene)| scene.is_geometry_attached(geometry_id))
+        {
+            return Err(Error::GeometryStillAttached);
+        }
+
+        self.geometries.remove(geometry_id.0);
+
+        Ok(())
+    }
+
+    /// Remove the scene of the given id.
+    ///
+    /// The geometries attached to the scene are not removed, they
+    /// are only detached from the scene.
+    ///
+    /// A scene that is being committed by
+    /// [`Self::commit_scene_async()`] cannot be removed until
+    /// [`Self::finish_commit()`], [`Error::SceneCommitting`] is
+    /// returned otherwise.
+    pub fn remove_scene(&mut self, scene_id: SceneID) -> Result<(), Error> {
+        self.collect_abandoned_commits();
+
+        match self.scenes.get(scene_id.0) {
+            None => return Err(Error::SceneNotFound),
+            Some(Scene::Committing(_)) => return Err(Error::SceneCommitting),
+            Some(_) => {}
+        }
+
+        self.scenes.remove(scene_id.0);
+
+        Ok(())
     }
 
     pub fn intersect_scene(&self, scene_id: SceneID, ray: Ray) -> RayHit {
+        self.intersect_scene_with_culling(scene_id, ray, FaceCulling::None)
+    }
+
+    /// Intersect the ray with the scene of the given id like
+    /// [`Self::intersect_scene()`], skipping the faces given by
+    /// `culling`.
+    pub fn intersect_scene_with_culling(
+        &self,
+        scene_id: SceneID,
+        ray: Ray,
+        culling: FaceCulling,
+    ) -> RayHit {
+        // TODO: propagate the error to the user
+        match self
+            .scenes
+            .get(scene_id.0)
+            .expect("scene of given id is not available")
+        {
+            Scene::Uncommitted(_) => unreachable!("scene must be committed, currently uncommitted"),
+            Scene::Committing(_) => {
+                unreachable!("scene must be committed, currently being committed")
+            }
+            Scene::Committed(scene) => match culling.filter() {
+                None => scene.intersect(ray),
+                filter => scene.intersect_with_context(
+                    ray,
+                    &mut IntersectContext {
+                        filter,
+                        ..Default::default()
+                    },
+                ),
+            },
+        }
+    }
+
+    /// Axis aligned bounding box of the scene of the given id.
+    pub fn scene_bounds(&self, scene_id: SceneID) -> Aabb {
         // TODO: propagate the error to the user
         match self
             .scenes
@@ -117,15 +623,104 @@ impl Embree {
             .expect("scene of given id is not available")
         {
             Scene::Uncommitted(_) => unreachable!("scene must be committed, currently uncommitted"),
-            Scene::Committed(scene) => scene.intersect(ray),
+            Scene::Committing(_) => {
+                unreachable!("scene must be committed, currently being committed")
+            }
+            Scene::Committed(scene) => scene.bounds(),
         }
     }
 
+    /// Linear bounds of the scene of the given id, the bounding
+    /// boxes of the scene at the start and the end of the motion
+    /// time range.
+    pub fn scene_linear_bounds(&self, scene_id: SceneID) -> LinearAabb {
+        // TODO: propagate the error to the user
+        match self
+            .scenes
+            .get(scene_id.0)
+            .expect("scene of given id is not available")
+        {
+            Scene::Uncommitted(_) => unreachable!("scene must be committed, currently uncommitted"),
+            Scene::Committing(_) => {
+                unreachable!("scene must be committed, currently being committed")
+            }
+            Scene::Committed(scene) => scene.linear_bounds(),
+        }
+    }
+
+    /// Axis aligned bounding box of the geometry of the given id.
+    pub fn geometry_bounds(&self, geometry_id: GeometryID) -> Aabb {
+        // TODO: propagate the error to the user
+        self.geometries
+            .get(geometry_id.0)
+            .expect("geometry of given id is not available")
+            .bounds()
+    }
+
+    /// Find the closest hit of the ray with the scene of the given
+    /// id, returns [`None`] if the ray does not hit anything.
+    pub fn closest_hit(&self, scene_id: SceneID, ray: Ray) -> Option<HitInfo> {
+        self.closest_hit_with_culling(scene_id, ray, FaceCulling::None)
+    }
+
+    /// Find the closest hit like [`Self::closest_hit()`], skipping
+    /// the faces given by `culling`.
+    pub fn closest_hit_with_culling(
+        &self,
+        scene_id: SceneID,
+        ray: Ray,
+        culling: FaceCulling,
+    ) -> Option<HitInfo> {
+        let ray_hit = self.intersect_scene_with_culling(scene_id, ray, culling);
+
+        if ray_hit.hit.geomID == INVALID_GEOMETRY_ID {
+            return None;
+        }
+
+        let origin = Vec3::new(ray_hit.ray.org_x, ray_hit.ray.org_y, ray_hit.ray.org_z);
+        let direction = Vec3::new(ray_hit.ray.dir_x, ray_hit.ray.dir_y, ray_hit.ray.dir_z);
+
+        let geometry_id = *self
+            .get_geometry_id_from_geometry_scene_id(scene_id, &GeometrySceneID(ray_hit.hit.geomID))
+            .expect("hit geometry must be attached to the scene");
+
+        let instance_ids = ray_hit
+            .hit
+            .instID
+            .iter()
+            .take_while(|id| **id != INVALID_GEOMETRY_ID)
+            .map(|id| GeometrySceneID(*id))
+            .collect();
+
+        Some(HitInfo {
+            distance: ray_hit.ray.tfar,
+            point: origin + direction * ray_hit.ray.tfar,
+            normal: Vec3::new(ray_hit.hit.Ng_x, ray_hit.hit.Ng_y, ray_hit.hit.Ng_z).normalize(),
+            uv: (ray_hit.hit.u, ray_hit.hit.v),
+            primitive_index: ray_hit.hit.primID,
+            geometry_id,
+            instance_ids,
+        })
+    }
+
+    /// Get the [`GeometryID`] of the geometry that is attached to
+    /// the scene of the given id as `geometry_scene_id`.
+    ///
+    /// The same geometry can be attached to multiple scenes and
+    /// embree gives each attachment its own [`GeometrySceneID`], so
+    /// the scene that was queried (for example the scene that was
+    /// intersected) must be provided.
     pub fn get_geometry_id_from_geometry_scene_id(
         &self,
+        scene_id: SceneID,
         geometry_scene_id: &GeometrySceneID,
     ) -> Option<&GeometryID> {
-        self.geometry_id_map.get(geometry_scene_id)
+        // TODO: propagate the error to the user
+        self.scenes
+            .get(scene_id.0)
+            .expect("scene of given id is not available")
+            .get_attached_geometries()
+            .get(geometry_scene_id)
     }
 }
 
@@ -135,14 +730,206 @@ impl Default for Embree {
     }
 }
 
+/// Commit of a scene that multiple threads can join, created by
+/// [`Embree::join_commit_scene()`]
+///
+/// Every thread that should help build the scene calls
+/// [`Self::join()`] on a shared reference to the `JoinCommit`. Once
+/// all the threads have returned from [`Self::join()`],
+/// [`Self::finish()`] gives the new id of the committed scene.
+///
+/// The `JoinCommit` borrows [`Embree`] mutably, so the scene and its
+/// geometries cannot change while the threads are building it.
+///
+/// If embree does not support joining commits (see
+/// [`DeviceInfo::join_commit_supported`]), [`Self::join()`] returns
+/// immediately and [`Self::finish()`] builds the scene on the calling
+/// thread.
+pub struct JoinCommit<'a> {
+    embree: &'a mut Embree,
+    id: SceneID,
+    /// [`None`] if the scene is committed already
+    scene: Option<sys::RTCScene>,
+    join_supported: bool,
+    joined: AtomicBool,
+    /// First error reported to any of the joined threads, embree
+    /// keeps the errors per thread
+    error: AtomicU32,
+    /// See [`Embree::commit_memory_start()`]
+    memory_start: Option<usize>,
+}
+
+// only the scene is accessed through a shared reference, and
+// `rtcJoinCommitScene()` can be called from multiple threads
+unsafe impl Sync for JoinCommit<'_> {}
+
+impl JoinCommit<'_> {
+    /// Join the commit of the scene, returns once the scene is
+    /// built.
+    pub fn join(&self) {
+        if !self.join_supported {
+            return;
+        }
+
+        if let Some(scene) = self.scene {
+            self.joined.store(true, Ordering::SeqCst);
+            unsafe {
+                sys::rtcJoinCommitScene(scene);
+            }
+            self.store_error();
+        }
+    }
+
+    /// Keep the error of the calling thread if no error was kept
+    /// yet.
+    fn store_error(&self) {
+        let error = self.embree.device.get_error();
+        if error != sys::RTCError_RTC_ERROR_NONE {
+            let _ = self.error.compare_exchange(
+                sys::RTCError_RTC_ERROR_NONE,
+                error,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            );
+        }
+    }
+
+    /// Finish the commit and return the new id of the scene, the
+    /// calling thread builds the scene if no thread joined the
+    /// commit.
+    ///
+    /// If embree reported an error to any of the threads,
+    /// [`Error::MemoryLimitExceeded`] or [`Error::CommitFailed`] is
+    /// returned and the scene stays uncommitted under the same id.
+    pub fn finish(self) -> Result<SceneID, Error> {
+        match self.scene {
+            Some(scene) if !self.join_supported => {
+                unsafe {
+                    sys::rtcCommitScene(scene);
+                }
+                self.store_error();
+            }
+            Some(_) if !self.joined.load(Ordering::SeqCst) => self.join(),
+            _ => {}
+        }
+
+        Device::error_to_result(self.error.load(Ordering::SeqCst))?;
+
+        let scene = match self.embree.scenes.remove(self.id.0).unwrap() {
+            Scene::Uncommitted(scene) => {
+                let mut scene = scene.into_committed();
+                scene.memory_usage = self.embree.commit_memory_growth(self.memory_start);
+                scene
+            }
+            Scene::Committed(scene) => scene,
+            Scene::Committing(_) => unreachable!("scene is being committed already"),
+        };
+
+        Ok(self.embree.add_scene_committed(scene))
+    }
+}
+
+/// Result of a commit on a background thread, gives the scene back
+/// on failure so it stays uncommitted
+type CommitResult = Result<SceneCommitted, (SceneUncommitted, Error)>;
+
+/// Commit of a scene running on a background thread, created by
+/// [`Embree::commit_scene_async()`]
+///
+/// If the handle is dropped without [`Embree::finish_commit()`], the
+/// scene keeps its id and becomes available again once the commit is
+/// done and [`Embree`] is changed next. It stays uncommitted if the
+/// commit failed, the error is lost.
+#[derive(Debug)]
+#[must_use = "the new id of the scene is given by `Embree::finish_commit()`"]
+pub struct CommitHandle {
+    id: SceneID,
+    thread: JoinHandle<()>,
+    /// Shared with the commit thread and the committing scene
+    result: Arc<Mutex<Option<CommitResult>>>,
+}
+
+impl CommitHandle {
+    /// Check if the scene is built, [`Embree::finish_commit()`] does
+    /// not block once it is.
+    pub fn is_finished(&self) -> bool {
+        self.thread.is_finished()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// Geometry is still attached to at least one scene
+    GeometryStillAttached,
+    /// Commit of the scene was cancelled by the progress monitor
+    CommitCancelled,
+    /// Allocation would exceed the memory limit of the device
+    MemoryLimitExceeded,
+    /// Embree was compiled without the feature
+    FeatureNotSupported(Feature),
+    /// Embree reported the error while committing the scene
+    CommitFailed(sys::RTCError),
+    /// Scene is being committed on a background thread
+    SceneCommitting,
+    /// No scene of the given id, it might have been removed or
+    /// committed under a new id
+    SceneNotFound,
+    /// No geometry of the given id, it might have been removed
+    GeometryNotFound,
+    /// Scene is committed already and cannot be changed
+    SceneCommitted,
+    /// Geometry is not attached to the scene
+    GeometryNotAttached,
+    /// Scene must be committed before it can be queried
+    SceneNotCommitted,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::GeometryStillAttached => {
+                write!(f, "geometry is still attached to at least one scene")
+            }
+            Error::CommitCancelled => write!(f, "commit of the scene was cancelled"),
+            Error::MemoryLimitExceeded => {
+                write!(f, "allocation would exceed the memory limit of the device")
+            }
+            Error::FeatureNotSupported(feature) => {
+                write!(f, "embree was compiled without {} support", feature)
+            }
+            Error::CommitFailed(error) => {
+                write!(f, "embree failed to commit the scene (error {})", error)
+            }
+            Error::SceneCommitting => {
+                write!(f, "scene is being committed on a background thread")
+            }
+            Error::SceneNotFound => write!(f, "scene of given id is not available"),
+            Error::GeometryNotFound => write!(f, "geometry of given id is not available"),
+            Error::SceneCommitted => write!(f, "scene is committed already"),
+            Error::GeometryNotAttached => write!(f, "geometry is not attached to the scene"),
+            Error::SceneNotCommitted => write!(f, "scene must be committed before querying"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
 #[derive(Debug)]
 pub(crate) struct Device {
     device: sys::RTCDevice,
+    /// User data of the memory monitor of the device, shared with the
+    /// threads of [`Embree::commit_scene_async()`] so that it outlives
+    /// them
+    memory_monitor: Arc<MemoryMonitor>,
 }
 
 impl Drop for Device {
     fn drop(&mut self) {
         unsafe {
+            // the device is only freed once all its scenes and
+            // geometries are, which may happen after the monitor is
+            // freed
+            sys::rtcSetDeviceMemoryMonitorFunction(self.device, None, std::ptr::null_mut());
             sys::rtcReleaseDevice(self.device);
         }
         self.device = std::ptr::null_mut();
@@ -157,7 +944,40 @@ impl Device {
         // TODO: add device config support
         let device = unsafe { sys::rtcNewDevice(std::ptr::null()) };
         assert_ne!(device, std::ptr::null_mut());
-        Self { device }
+
+        let memory_monitor = Arc::new(MemoryMonitor::new());
+        unsafe {
+            sys::rtcSetDeviceMemoryMonitorFunction(
+                device,
+                Some(memory::memory_monitor_func),
+                Arc::as_ptr(&memory_monitor) as *mut c_void,
+            );
+        }
+
+        Self {
+            device,
+            memory_monitor,
+        }
+    }
+
+    /// Get the first error of the device since the last call and
+    /// clear it.
+    pub fn get_error(&self) -> sys::RTCError {
+        unsafe { sys::rtcGetDeviceError(self.device) }
+    }
+
+    /// Convert the error of the device to a [`Result`].
+    ///
+    /// Running out of memory is reported as
+    /// [`Error::MemoryLimitExceeded`] since it is caused by the
+    /// memory limit of the device (see [`Embree::set_memory_limit()`])
+    /// unless the system is out of memory.
+    fn error_to_result(error: sys::RTCError) -> Result<(), Error> {
+        match error {
+            sys::RTCError_RTC_ERROR_NONE => Ok(()),
+            sys::RTCError_RTC_ERROR_OUT_OF_MEMORY => Err(Error::MemoryLimitExceeded),
+            error => Err(Error::CommitFailed(error)),
+        }
     }
 
     /// # Safety
@@ -185,21 +1005,85 @@ pub struct GeometryID(Index);
 #[derive(Debug)]
 pub(crate) struct SceneUncommitted {
     scene: sys::RTCScene,
+    /// Map from GeometrySceneID to GeometryID of the geometries
+    /// attached to the scene, useful for when embree gives the
+    /// GeometrySceneID but the user must be provided with the
+    /// GeometryID.
+    attached_geometries: HashMap<GeometrySceneID, GeometryID>,
 }
 #[derive(Debug)]
 pub(crate) struct SceneCommitted {
     scene: sys::RTCScene,
+    /// Map from GeometrySceneID to GeometryID of the geometries
+    /// attached to the scene, useful for when embree gives the
+    /// GeometrySceneID but the user must be provided with the
+    /// GeometryID.
+    attached_geometries: HashMap<GeometrySceneID, GeometryID>,
+    /// Bytes allocated by embree while the scene was committed, see
+    /// [`Embree::scene_memory_usage()`]
+    memory_usage: Option<usize>,
+}
+
+/// Scene that is being committed on a background thread, the
+/// thread owns the scene itself
+#[derive(Debug)]
+pub(crate) struct SceneCommitting {
+    /// Map from GeometrySceneID to GeometryID of the geometries
+    /// attached to the scene, so the geometries cannot be changed or
+    /// removed during the commit.
+    attached_geometries: HashMap<GeometrySceneID, GeometryID>,
+    /// Filled by the commit thread, see [`CommitHandle`]
+    result: Arc<Mutex<Option<CommitResult>>>,
 }
 
 #[derive(Debug)]
 pub(crate) enum Scene {
     Uncommitted(SceneUncommitted),
     Committed(SceneCommitted),
+    Committing(SceneCommitting),
 }
 
 unsafe impl Sync for Scene {}
 unsafe impl Send for Scene {}
 
+// the scenes are moved to the background thread of
+// `Embree::commit_scene_async()`
+unsafe impl Send for SceneUncommitted {}
+unsafe impl Send for SceneCommitted {}
+
+impl Scene {
+    pub fn get_attached_geometries(&self) -> &HashMap<GeometrySceneID, GeometryID> {
+        match self {
+            Scene::Uncommitted(scene) => &scene.attached_geometries,
+            Scene::Committed(scene) => &scene.attached_geometries,
+            Scene::Committing(scene) => &scene.attached_geometries,
+        }
+    }
+
+    pub fn is_geometry_attached(&self, geometry_id: GeometryID) -> bool {
+        self.get_attached_geometries()
+            .values()
+            .any(|id| *id == geometry_id)
+    }
+
+    /// Commit the scene again if it is committed, see
+    /// [`SceneCommitted::recommit()`].
+    ///
+    /// The scene is only partially built if the commit fails, so it
+    /// becomes uncommitted.
+    pub fn recommit(&mut self) -> Result<(), Error> {
+        let Scene::Committed(scene) = self else {
+            return Ok(());
+        };
+
+        let result = scene.recommit();
+        if result.is_err() {
+            *self = Scene::Uncommitted(scene.take_uncommitted());
+        }
+        result
+    }
+}
+
 impl Drop for SceneUncommitted {
     fn drop(&mut self) {
         unsafe {
@@ -219,30 +1103,125 @@ impl Drop for SceneCommitted {
 }
 
 impl SceneUncommitted {
-    pub(crate) fn new(device: &Device) -> Self {
+    /// Returns [`Error::MemoryLimitExceeded`] if embree fails to
+    /// allocate the scene.
+    pub(crate) fn new(device: &Device) -> Result<Self, Error> {
         let scene = unsafe { sys::rtcNewScene(device.get_device()) };
-        assert_ne!(scene, std::ptr::null_mut());
-        Self { scene }
+        if scene.is_null() {
+            // clear the error of the device
+            device.get_error();
+            return Err(Error::MemoryLimitExceeded);
+        }
+        // the filter of the intersect context is only invoked with
+        // the flag set, see `FaceCulling`
+        unsafe { sys::rtcSetSceneFlags(scene, FILTER_FUNCTION_SCENE_FLAG) };
+        Ok(Self {
+            scene,
+            attached_geometries: HashMap::new(),
+        })
     }
 
-    pub fn attach_geometry(&mut self, geometry: &Geometry) -> GeometrySceneID {
-        GeometrySceneID(unsafe {
+    pub fn attach_geometry(
+        &mut self,
+        geometry_id: GeometryID,
+        geometry: &Geometry,
+    ) -> GeometrySceneID {
+        let geometry_scene_id = GeometrySceneID(unsafe {
             sys::rtcAttachGeometry(self.get_scene(), geometry.get_geometry())
-        })
+        });
+
+        self.attached_geometries
+            .insert(geometry_scene_id, geometry_id);
+
+        geometry_scene_id
+    }
+
+    /// Detach the geometry from the scene, returns the
+    /// [`GeometrySceneID`] the geometry had in the scene if it was
+    /// attached.
+    pub fn detach_geometry(&mut self, geometry_id: GeometryID) -> Option<GeometrySceneID> {
+        let geometry_scene_id =
+            self.attached_geometries
+                .iter()
+                .find_map(|(geometry_scene_id, id)| {
+                    (*id == geometry_id).then_some(*geometry_scene_id)
+                })?;
+
+        unsafe {
+            sys::rtcDetachGeometry(self.get_scene(), geometry_scene_id.0);
+        }
+
+        self.attached_geometries.remove(&geometry_scene_id);
+
+        Some(geometry_scene_id)
     }
 
-    pub fn commit(self) -> SceneCommitted {
+    /// Build the BVH of the scene and convert it to a
+    /// [`SceneCommitted`], the scene is given back along with the
+    /// error if embree fails to build it.
+    ///
+    /// Can be called on any thread, the error is taken from the
+    /// calling thread.
+    pub fn commit(self) -> Result<SceneCommitted, (Self, Error)> {
         unsafe {
             sys::rtcCommitScene(self.get_scene());
         }
 
+        match Device::error_to_result(unsafe { get_scene_device_error(self.scene) }) {
+            Ok(()) => Ok(self.into_committed()),
+            Err(error) => Err((self, error)),
+        }
+    }
+
+    /// Build the BVH of the scene without converting it to a
+    /// [`SceneCommitted`], `progress` is called with the fraction of
+    /// the build done so far and returns whether the build should
+    /// continue.
+    ///
+    /// Returns [`Error::CommitCancelled`] if the build was cancelled,
+    /// the scene is left as it was before the build.
+    pub fn build_with_progress(
+        &mut self,
+        mut progress: impl FnMut(f64) -> bool + Send,
+    ) -> Result<(), Error> {
+        // embree may call the progress monitor from multiple threads
+        let monitor = Mutex::new(ProgressMonitor {
+            progress: &mut progress,
+            cancelled: false,
+        });
+
+        unsafe {
+            sys::rtcSetSceneProgressMonitorFunction(
+                self.get_scene(),
+                Some(progress_monitor_func),
+                &monitor as *const _ as *mut c_void,
+            );
+            sys::rtcCommitScene(self.get_scene());
+            // the monitor is dropped at the end of this function
+            sys::rtcSetSceneProgressMonitorFunction(self.get_scene(), None, std::ptr::null_mut());
+        }
+
+        if monitor.into_inner().unwrap().cancelled {
+            Err(Error::CommitCancelled)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Convert the scene to a [`SceneCommitted`], the scene must
+    /// have been built already.
+    pub(crate) fn into_committed(mut self) -> SceneCommitted {
         // retain the scene so it is not dropped at the end of this
         // function
         unsafe {
             sys::rtcRetainScene(self.get_scene());
         }
 
-        SceneCommitted { scene: self.scene }
+        SceneCommitted {
+            scene: self.scene,
+            attached_geometries: std::mem::take(&mut self.attached_geometries),
+            memory_usage: None,
+        }
     }
 
     /// # Safety
@@ -255,20 +1234,128 @@ impl SceneUncommitted {
     }
 }
 
+/// User data of [`progress_monitor_func()`]
+struct ProgressMonitor<'a> {
+    progress: &'a mut (dyn FnMut(f64) -> bool + Send),
+    cancelled: bool,
+}
+
+unsafe extern "C" fn progress_monitor_func(ptr: *mut c_void, n: f64) -> bool {
+    let monitor = &*(ptr as *const Mutex<ProgressMonitor>);
+    let mut monitor = monitor.lock().unwrap();
+
+    // once cancelled, stay cancelled
+    if !monitor.cancelled {
+        monitor.cancelled = !(monitor.progress)(n.clamp(0.0, 1.0));
+    }
+
+    !monitor.cancelled
+}
+
+/// Get the first error since the last call of the device that the
+/// scene belongs to, embree keeps the errors per thread so this must
+/// be called on the thread that made the failing call.
+///
+/// # Safety
+///
+/// The scene must be valid.
+unsafe fn get_scene_device_error(scene: sys::RTCScene) -> sys::RTCError {
+    // the device is retained by `rtcGetSceneDevice()`
+    let device = sys::rtcGetSceneDevice(scene);
+    let error = sys::rtcGetDeviceError(device);
+    sys::rtcReleaseDevice(device);
+    error
+}
+
 impl SceneCommitted {
+    /// # Safety
+    ///
+    /// If not handled correctly, can lead to memory leaks or other
+    /// memory problems. It is always better to use the Rust API
+    /// instead of trying to get access to the FFI parts directly.
+    pub unsafe fn get_scene(&self) -> sys::RTCScene {
+        self.scene
+    }
+
+    pub fn bounds(&self) -> Aabb {
+        let mut bounds = Bounds::default();
+        unsafe {
+            sys::rtcGetSceneBounds(self.scene, &mut bounds);
+        }
+        bounds.into()
+    }
+
+    pub fn linear_bounds(&self) -> LinearAabb {
+        let mut bounds = LinearBounds {
+            bounds0: Bounds::default(),
+            bounds1: Bounds::default(),
+        };
+        unsafe {
+            sys::rtcGetSceneLinearBounds(self.scene, &mut bounds);
+        }
+        LinearAabb {
+            start: bounds.bounds0.into(),
+            end: bounds.bounds1.into(),
+        }
+    }
+
+    /// Commit the scene again, required for changes made to the
+    /// attached geometries (such as enabling or disabling them) to
+    /// have effect.
+    pub fn recommit(&mut self) -> Result<(), Error> {
+        // the scene is rebuilt, so the memory of the first commit is
+        // not known to be in use anymore
+        self.memory_usage = None;
+        unsafe {
+            sys::rtcCommitScene(self.scene);
+        }
+
+        Device::error_to_result(unsafe { get_scene_device_error(self.scene) })
+    }
+
+    /// Move the scene and its geometries to a [`SceneUncommitted`],
+    /// leaving this scene without geometries.
+    pub(crate) fn take_uncommitted(&mut self) -> SceneUncommitted {
+        // the scene is released by both
+        unsafe {
+            sys::rtcRetainScene(self.scene);
+        }
+
+        SceneUncommitted {
+            scene: self.scene,
+            attached_geometries: std::mem::take(&mut self.attached_geometries),
+        }
+    }
+
     /// Intersect ray with the scene.
     ///
     /// TODO: add support for the other intersection types along with
     /// custom context creation.
     pub fn intersect(&self, ray: Ray) -> RayHit {
-        let mut context = IntersectContext::default();
+        self.intersect_with_context(ray, &mut IntersectContext::default())
+    }
 
+    /// Intersect ray with the scene using the given context.
+    pub fn intersect_with_context(&self, ray: Ray, context: &mut IntersectContext) -> RayHit {
         let mut rayhit = RayHit {
             ray,
             hit: Hit::default(),
         };
 
-        unsafe { sys::rtcIntersect1(self.scene, &mut context, &mut rayhit) }
+        #[cfg(not(feature = "embree4"))]
+        unsafe {
+            sys::rtcIntersect1(self.scene, context, &mut rayhit)
+        }
+
+        // embree 4 only invokes the filter of the arguments for
+        // geometries that enable it, unless the query asks for it
+        #[cfg(feature = "embree4")]
+        unsafe {
+            if context.filter.is_some() {
+                context.flags |= sys::RTCRayQueryFlags_RTC_RAY_QUERY_FLAG_INVOKE_ARGUMENT_FILTER;
+            }
+            sys::rtcIntersect1(self.scene, &mut rayhit, context)
+        }
 
         rayhit
     }
@@ -293,6 +1380,14 @@ impl Ray {
             flags: 0,
         }
     }
+
+    /// Set the mask of the ray, the ray only hits geometries whose
+    /// mask shares at least one bit with the ray mask. By default the
+    /// ray hits all geometries.
+    pub fn with_mask(mut self, mask: u32) -> Self {
+        self.mask = mask;
+        self
+    }
 }
 
 pub type Hit = sys::RTCHit;
@@ -307,25 +1402,169 @@ impl Default for Hit {
             v: 0.0,
             primID: sys::RTC_INVALID_GEOMETRY_ID,
             geomID: sys::RTC_INVALID_GEOMETRY_ID,
-            instID: [sys::RTC_INVALID_GEOMETRY_ID],
+            instID: [sys::RTC_INVALID_GEOMETRY_ID; sys::RTC_MAX_INSTANCE_LEVEL_COUNT as usize],
+            #[cfg(feature = "embree4")]
+            instPrimID: [sys::RTC_INVALID_GEOMETRY_ID; sys::RTC_MAX_INSTANCE_LEVEL_COUNT as usize],
+        }
+    }
+}
+
+pub type RayHit = sys::RTCRayHit;
+
+/// Information about the closest hit of a ray with a scene
+#[derive(Debug, Clone, PartialEq)]
+pub struct HitInfo {
+    /// Distance along the ray to the hit, in multiples of the length
+    /// of the ray direction.
+    pub distance: f32,
+    /// Hit point in world space.
+    pub point: Vec3,
+    /// Normalized geometric normal at the hit point.
+    pub normal: Vec3,
+    /// Barycentric coordinates of the hit point within the
+    /// primitive.
+    pub uv: (f32, f32),
+    /// Index of the primitive (triangle, sphere, etc.) of the
+    /// geometry that was hit.
+    pub primitive_index: u32,
+    /// Geometry that was hit.
+    pub geometry_id: GeometryID,
+    /// Instance chain of the hit, the [`GeometrySceneID`] of each
+    /// instance that was traversed, from the top level scene
+    /// downwards. Empty if the geometry was hit directly.
+    pub instance_ids: Vec<GeometrySceneID>,
+}
+
+#[cfg(not(feature = "embree4"))]
+pub type IntersectContext = sys::RTCIntersectContext;
+
+/// Embree 4 replaces the intersect context by the intersect
+/// arguments, which hold the flags and the filter function instead
+#[cfg(feature = "embree4")]
+pub type IntersectContext = sys::RTCIntersectArguments;
+
+#[cfg(not(feature = "embree4"))]
+const FILTER_FUNCTION_SCENE_FLAG: sys::RTCSceneFlags =
+    sys::RTCSceneFlags_RTC_SCENE_FLAG_CONTEXT_FILTER_FUNCTION;
+#[cfg(feature = "embree4")]
+const FILTER_FUNCTION_SCENE_FLAG: sys::RTCSceneFlags =
+    sys::RTCSceneFlags_RTC_SCENE_FLAG_FILTER_FUNCTION_IN_ARGUMENTS;
+
+#[cfg(not(feature = "embree4"))]
+impl Default for IntersectContext {
+    fn default() -> Self {
+        Self {
+            flags: sys::RTCIntersectContextFlags_RTC_INTERSECT_CONTEXT_FLAG_INCOHERENT,
+            filter: None,
+            #[cfg(any(
+                feature = "max-instance-level-2",
+                feature = "max-instance-level-4",
+                feature = "max-instance-level-8"
+            ))]
+            instStackSize: 0,
+            instID: [sys::RTC_INVALID_GEOMETRY_ID; sys::RTC_MAX_INSTANCE_LEVEL_COUNT as usize],
+        }
+    }
+}
+
+#[cfg(feature = "embree4")]
+impl Default for IntersectContext {
+    fn default() -> Self {
+        Self {
+            flags: sys::RTCRayQueryFlags_RTC_RAY_QUERY_FLAG_INCOHERENT,
+            feature_mask: sys::RTCFeatureFlags_RTC_FEATURE_FLAG_ALL,
+            // embree uses a default ray query context if none is given
+            context: std::ptr::null_mut(),
+            filter: None,
+            intersect: None,
+        }
+    }
+}
+
+/// Faces to skip during a query, see
+/// [`Embree::closest_hit_with_culling()`]
+///
+/// A face is back facing if its geometry normal points along the
+/// ray direction, the same convention embree uses for backface
+/// culling. Culling is done with a context filter function, so
+/// embree must be compiled with filter function support. Compiling
+/// embree with the `backface-culling` feature culls back faces for
+/// all queries instead, see [`DeviceInfo::backface_culling_enabled`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FaceCulling {
+    #[default]
+    None,
+    BackFaces,
+}
+
+impl FaceCulling {
+    fn filter(self) -> sys::RTCFilterFunctionN {
+        match self {
+            FaceCulling::None => None,
+            FaceCulling::BackFaces => Some(backface_culling_filter_func),
+        }
+    }
+}
+
+/// Context filter function that rejects the back facing hits.
+unsafe extern "C" fn backface_culling_filter_func(args: *const sys::RTCFilterFunctionNArguments) {
+    let args = &*args;
+    let n = args.N as usize;
+    // rays and hits are in SOA layout, `field` is the index of the
+    // field in `RTCRay` or `RTCHit`
+    let ray = |field: usize, i: usize| *(args.ray as *const f32).add(field * n + i);
+    let hit = |field: usize, i: usize| *(args.hit as *const f32).add(field * n + i);
+
+    (0..n).for_each(|i| {
+        let valid = &mut *args.valid.add(i);
+        if *valid == 0 {
+            return;
+        }
+
+        let direction = Vec3::new(ray(4, i), ray(5, i), ray(6, i));
+        let normal = Vec3::new(hit(0, i), hit(1, i), hit(2, i));
+        if direction.dot(&normal) >= 0.0 {
+            *valid = 0;
+        }
+    });
+}
+
+pub type PointQuery = sys::RTCPointQuery;
+
+pub type PointQueryContext = sys::RTCPointQueryContext;
+
+impl Default for PointQueryContext {
+    fn default() -> Self {
+        Self {
+            world2inst: [[0.0; 16]; sys::RTC_MAX_INSTANCE_LEVEL_COUNT as usize],
+            inst2world: [[0.0; 16]; sys::RTC_MAX_INSTANCE_LEVEL_COUNT as usize],
+            instID: [sys::RTC_INVALID_GEOMETRY_ID; sys::RTC_MAX_INSTANCE_LEVEL_COUNT as usize],
+            #[cfg(feature = "embree4")]
+            instPrimID: [sys::RTC_INVALID_GEOMETRY_ID; sys::RTC_MAX_INSTANCE_LEVEL_COUNT as usize],
+            instStackSize: 0,
         }
     }
 }
 
-pub type RayHit = sys::RTCRayHit;
-
-pub type IntersectContext = sys::RTCIntersectContext;
+pub type Bounds = sys::RTCBounds;
 
-impl Default for IntersectContext {
+impl Default for Bounds {
     fn default() -> Self {
         Self {
-            flags: sys::RTCIntersectContextFlags_RTC_INTERSECT_CONTEXT_FLAG_INCOHERENT,
-            filter: None,
-            instID: [sys::RTC_INVALID_GEOMETRY_ID],
+            lower_x: f32::INFINITY,
+            lower_y: f32::INFINITY,
+            lower_z: f32::INFINITY,
+            align0: 0.0,
+            upper_x: f32::NEG_INFINITY,
+            upper_y: f32::NEG_INFINITY,
+            upper_z: f32::NEG_INFINITY,
+            align1: 0.0,
         }
     }
 }
 
+pub type LinearBounds = sys::RTCLinearBounds;
+
 /// 3 element vector
 ///
 /// Do not add or remove elements!
@@ -341,6 +1580,139 @@ impl Vec3 {
     pub fn new(x: f32, y: f32, z: f32) -> Self {
         Self { x, y, z }
     }
+
+    pub fn length(&self) -> f32 {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    pub fn normalize(&self) -> Self {
+        *self * (1.0 / self.length())
+    }
+
+    pub fn dot(&self, other: &Self) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(&self, other: &Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Component wise minimum
+    pub fn min(&self, other: &Self) -> Self {
+        Self::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    /// Component wise maximum
+    pub fn max(&self, other: &Self) -> Self {
+        Self::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+}
+
+/// Axis aligned bounding box
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl Aabb {
+    pub fn new(min: Vec3, max: Vec3) -> Self {
+        Self { min, max }
+    }
+
+    /// Bounding box that contains nothing, the union of it with any
+    /// other bounding box is the other bounding box.
+    pub fn empty() -> Self {
+        Self::new(
+            Vec3::new(f32::INFINITY, f32::INFINITY, f32::INFINITY),
+            Vec3::new(f32::NEG_INFINITY, f32::NEG_INFINITY, f32::NEG_INFINITY),
+        )
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+    }
+
+    pub fn center(&self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    pub fn size(&self) -> Vec3 {
+        self.max - self.min
+    }
+
+    /// Smallest bounding box containing both the bounding boxes.
+    pub fn union(&self, other: &Self) -> Self {
+        Self::new(self.min.min(&other.min), self.max.max(&other.max))
+    }
+
+    /// Smallest bounding box containing the bounding box and the
+    /// point.
+    pub fn grow(&self, point: Vec3) -> Self {
+        Self::new(self.min.min(&point), self.max.max(&point))
+    }
+}
+
+impl From<Bounds> for Aabb {
+    fn from(bounds: Bounds) -> Self {
+        Self::new(
+            Vec3::new(bounds.lower_x, bounds.lower_y, bounds.lower_z),
+            Vec3::new(bounds.upper_x, bounds.upper_y, bounds.upper_z),
+        )
+    }
+}
+
+/// Bounding boxes at the start and the end of the motion time
+/// range, the bounds at any time in between are the linear
+/// interpolation of the two.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearAabb {
+    pub start: Aabb,
+    pub end: Aabb,
+}
+
+impl std::ops::Add for Vec3 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl std::ops::Sub for Vec3 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl std::ops::Neg for Vec3 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl std::ops::Mul<f32> for Vec3 {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
 }
 
 /// Vertex that stores the position
@@ -364,6 +1736,7 @@ impl Vert {
 /// triangle
 ///
 /// Do not add or remove elements!
+#[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
 pub struct Triangle {
     pub i0: u32,
@@ -377,6 +1750,27 @@ impl Triangle {
     }
 }
 
+/// Stores the 4 indices from the [`Vert`] buffer that form the quad
+///
+/// Embree splits the quad into the triangles `i0, i1, i3` and `i2,
+/// i3, i1`, so the vertices should be planar.
+///
+/// Do not add or remove elements!
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
+pub struct Quad {
+    pub i0: u32,
+    pub i1: u32,
+    pub i2: u32,
+    pub i3: u32,
+}
+
+impl Quad {
+    pub fn new(i0: u32, i1: u32, i2: u32, i3: u32) -> Self {
+        Self { i0, i1, i2, i3 }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct GeometrySceneID(pub u32);
 
@@ -384,6 +1778,7 @@ pub struct GeometrySceneID(pub u32);
 #[derive(Debug)]
 pub(crate) enum Geometry {
     Triangle(GeometryTriangle),
+    Quad(GeometryQuad),
     Sphere(GeometrySphere),
 }
 
@@ -399,14 +1794,120 @@ impl Geometry {
     pub unsafe fn get_geometry(&self) -> sys::RTCGeometry {
         match self {
             Geometry::Triangle(geometry) => geometry.get_geometry(),
+            Geometry::Quad(geometry) => geometry.get_geometry(),
             Geometry::Sphere(geometry) => geometry.get_geometry(),
         }
     }
+
+    /// Axis aligned bounding box of the geometry, computed from the
+    /// stored buffers.
+    pub fn bounds(&self) -> Aabb {
+        match self {
+            Geometry::Triangle(geometry) => geometry.bounds(),
+            Geometry::Quad(geometry) => geometry.bounds(),
+            Geometry::Sphere(geometry) => geometry.bounds(),
+        }
+    }
+
+    /// Enable or disable the geometry, the scenes that the geometry
+    /// is attached to must be committed for the change to have
+    /// effect.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        unsafe {
+            if enabled {
+                sys::rtcEnableGeometry(self.get_geometry());
+            } else {
+                sys::rtcDisableGeometry(self.get_geometry());
+            }
+        }
+
+        // embree 3 cannot be asked whether a geometry is enabled
+        match self {
+            Geometry::Triangle(geometry) => geometry.enabled = enabled,
+            Geometry::Quad(geometry) => geometry.enabled = enabled,
+            Geometry::Sphere(geometry) => geometry.enabled = enabled,
+        }
+    }
+
+    /// Check if the geometry is enabled, see [`Self::set_enabled()`].
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            Geometry::Triangle(geometry) => geometry.enabled,
+            Geometry::Quad(geometry) => geometry.enabled,
+            Geometry::Sphere(geometry) => geometry.enabled,
+        }
+    }
+
+    /// Set the mask of the geometry, the scenes that the geometry is
+    /// attached to must be committed for the change to have effect.
+    pub fn set_mask(&self, mask: u32) {
+        unsafe {
+            sys::rtcSetGeometryMask(self.get_geometry(), mask);
+            sys::rtcCommitGeometry(self.get_geometry());
+        }
+    }
+}
+
+/// Create a new geometry of the given type, returns
+/// [`Error::MemoryLimitExceeded`] if embree fails to allocate it.
+fn new_geometry(
+    device: &Device,
+    geometry_type: sys::RTCGeometryType,
+) -> Result<sys::RTCGeometry, Error> {
+    let geometry = unsafe { sys::rtcNewGeometry(device.get_device(), geometry_type) };
+    if geometry.is_null() {
+        // clear the error of the device
+        device.get_error();
+        return Err(Error::MemoryLimitExceeded);
+    }
+    Ok(geometry)
+}
+
+/// Allocate a new buffer of the geometry and copy `data` into it,
+/// returns [`Error::MemoryLimitExceeded`] if embree fails to allocate
+/// the buffer.
+///
+/// # Safety
+///
+/// The geometry must be valid and `format` must match `T`.
+unsafe fn set_new_geometry_buffer<T: Copy>(
+    device: &Device,
+    geometry: sys::RTCGeometry,
+    buffer_type: sys::RTCBufferType,
+    slot: u32,
+    format: sys::RTCFormat,
+    data: &[T],
+) -> Result<(), Error> {
+    let buffer = sys::rtcSetNewGeometryBuffer(
+        geometry,
+        buffer_type,
+        slot,
+        format,
+        std::mem::size_of::<T>(),
+        data.len(),
+    ) as *mut T;
+
+    // embree does not allocate empty buffers
+    if data.is_empty() {
+        return Ok(());
+    }
+    if buffer.is_null() {
+        // clear the error of the device
+        device.get_error();
+        return Err(Error::MemoryLimitExceeded);
+    }
+
+    std::slice::from_raw_parts_mut(buffer, data.len()).copy_from_slice(data);
+    Ok(())
 }
 
 #[derive(Debug)]
 pub(crate) struct GeometryTriangle {
     geometry: sys::RTCGeometry,
+    num_verts: usize,
+    num_triangles: usize,
+    num_time_steps: u32,
+    enabled: bool,
 }
 
 unsafe impl Sync for GeometryTriangle {}
@@ -423,52 +1924,202 @@ impl Drop for GeometryTriangle {
 }
 
 impl GeometryTriangle {
-    pub(crate) fn new(device: &Device, verts: &[Vert], indices: &[Triangle]) -> Self {
-        let geometry = unsafe {
-            sys::rtcNewGeometry(
-                device.get_device(),
-                sys::RTCGeometryType_RTC_GEOMETRY_TYPE_TRIANGLE,
-            )
+    pub(crate) fn new(
+        device: &Device,
+        verts: &[Vert],
+        indices: &[Triangle],
+    ) -> Result<Self, Error> {
+        Self::new_motion(device, &[verts], indices)
+    }
+
+    /// Create a triangle geometry with the vertex positions of
+    /// multiple time steps, the time steps are evenly spread over the
+    /// time range `[0, 1]` and embree linearly interpolates the
+    /// positions in between.
+    pub(crate) fn new_motion(
+        device: &Device,
+        time_step_verts: &[&[Vert]],
+        indices: &[Triangle],
+    ) -> Result<Self, Error> {
+        assert!(
+            !time_step_verts.is_empty(),
+            "at least one time step is required"
+        );
+        let num_verts = time_step_verts[0].len();
+        assert!(
+            time_step_verts.iter().all(|verts| verts.len() == num_verts),
+            "all time steps must have the same number of vertices"
+        );
+
+        let num_time_steps: u32 = time_step_verts.len().try_into().unwrap();
+        // released on drop if a buffer cannot be allocated
+        let geometry = Self {
+            geometry: new_geometry(device, sys::RTCGeometryType_RTC_GEOMETRY_TYPE_TRIANGLE)?,
+            num_verts,
+            num_triangles: indices.len(),
+            num_time_steps,
+            enabled: true,
         };
 
-        let verts_buffer: &mut [Vert] = unsafe {
-            std::slice::from_raw_parts_mut(
-                sys::rtcSetNewGeometryBuffer(
-                    geometry,
+        unsafe {
+            sys::rtcSetGeometryTimeStepCount(geometry.geometry, num_time_steps);
+
+            time_step_verts
+                .iter()
+                .enumerate()
+                .try_for_each(|(time_step, verts)| {
+                    set_new_geometry_buffer(
+                        device,
+                        geometry.geometry,
+                        sys::RTCBufferType_RTC_BUFFER_TYPE_VERTEX,
+                        time_step.try_into().unwrap(),
+                        sys::RTCFormat_RTC_FORMAT_FLOAT3,
+                        verts,
+                    )
+                })?;
+
+            set_new_geometry_buffer(
+                device,
+                geometry.geometry,
+                sys::RTCBufferType_RTC_BUFFER_TYPE_INDEX,
+                0,
+                sys::RTCFormat_RTC_FORMAT_UINT3,
+                indices,
+            )?;
+
+            sys::rtcSetGeometryBuildQuality(
+                geometry.geometry,
+                sys::RTCBuildQuality_RTC_BUILD_QUALITY_HIGH,
+            );
+            sys::rtcCommitGeometry(geometry.geometry);
+        }
+
+        Ok(geometry)
+    }
+
+    /// # Safety
+    ///
+    /// If not handled correctly, can lead to memory leaks or other
+    /// memory problems. It is always better to use the Rust API
+    /// instead of trying to get access to the FFI parts directly.
+    pub unsafe fn get_geometry(&self) -> sys::RTCGeometry {
+        self.geometry
+    }
+
+    pub fn get_num_time_steps(&self) -> u32 {
+        self.num_time_steps
+    }
+
+    /// Get the vertices stored in the vertex buffer of the geometry,
+    /// the vertices of the first time step for geometries with
+    /// motion.
+    pub fn get_verts(&self) -> &[Vert] {
+        self.get_verts_at_time_step(0)
+    }
+
+    /// Get the vertices stored in the vertex buffer of the given time
+    /// step of the geometry.
+    pub fn get_verts_at_time_step(&self, time_step: u32) -> &[Vert] {
+        assert!(time_step < self.num_time_steps);
+        unsafe {
+            std::slice::from_raw_parts(
+                sys::rtcGetGeometryBufferData(
+                    self.geometry,
                     sys::RTCBufferType_RTC_BUFFER_TYPE_VERTEX,
-                    0,
-                    sys::RTCFormat_RTC_FORMAT_FLOAT3,
-                    std::mem::size_of::<Vert>().try_into().unwrap(),
-                    verts.len().try_into().unwrap(),
-                ) as *mut Vert,
-                verts.len(),
+                    time_step,
+                ) as *const Vert,
+                self.num_verts,
             )
-        };
-
-        verts_buffer.copy_from_slice(verts);
+        }
+    }
 
-        let indices_buffer: &mut [Triangle] = unsafe {
-            std::slice::from_raw_parts_mut(
-                sys::rtcSetNewGeometryBuffer(
-                    geometry,
+    /// Get the triangles stored in the index buffer of the geometry.
+    pub fn get_triangles(&self) -> &[Triangle] {
+        unsafe {
+            std::slice::from_raw_parts(
+                sys::rtcGetGeometryBufferData(
+                    self.geometry,
                     sys::RTCBufferType_RTC_BUFFER_TYPE_INDEX,
                     0,
-                    sys::RTCFormat_RTC_FORMAT_UINT3,
-                    std::mem::size_of::<Triangle>().try_into().unwrap(),
-                    indices.len().try_into().unwrap(),
-                ) as *mut Triangle,
-                indices.len(),
+                ) as *const Triangle,
+                self.num_triangles,
             )
-        };
+        }
+    }
+
+    /// Bounds of the vertices referenced by the triangles over all
+    /// the time steps.
+    pub fn bounds(&self) -> Aabb {
+        (0..self.num_time_steps).fold(Aabb::empty(), |bounds, time_step| {
+            let verts = self.get_verts_at_time_step(time_step);
+            self.get_triangles()
+                .iter()
+                .flat_map(|triangle| [triangle.i0, triangle.i1, triangle.i2])
+                .fold(bounds, |bounds, index| {
+                    bounds.grow(verts[index as usize].pos)
+                })
+        })
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct GeometryQuad {
+    geometry: sys::RTCGeometry,
+    num_verts: usize,
+    num_quads: usize,
+    enabled: bool,
+}
+
+unsafe impl Sync for GeometryQuad {}
+unsafe impl Send for GeometryQuad {}
+
+impl Drop for GeometryQuad {
+    fn drop(&mut self) {
+        unsafe {
+            sys::rtcReleaseGeometry(self.geometry);
+        }
+
+        self.geometry = std::ptr::null_mut();
+    }
+}
 
-        indices_buffer.copy_from_slice(indices);
+impl GeometryQuad {
+    pub(crate) fn new(device: &Device, verts: &[Vert], indices: &[Quad]) -> Result<Self, Error> {
+        // released on drop if a buffer cannot be allocated
+        let geometry = Self {
+            geometry: new_geometry(device, sys::RTCGeometryType_RTC_GEOMETRY_TYPE_QUAD)?,
+            num_verts: verts.len(),
+            num_quads: indices.len(),
+            enabled: true,
+        };
 
         unsafe {
-            sys::rtcSetGeometryBuildQuality(geometry, sys::RTCBuildQuality_RTC_BUILD_QUALITY_HIGH);
-            sys::rtcCommitGeometry(geometry);
+            set_new_geometry_buffer(
+                device,
+                geometry.geometry,
+                sys::RTCBufferType_RTC_BUFFER_TYPE_VERTEX,
+                0,
+                sys::RTCFormat_RTC_FORMAT_FLOAT3,
+                verts,
+            )?;
+
+            set_new_geometry_buffer(
+                device,
+                geometry.geometry,
+                sys::RTCBufferType_RTC_BUFFER_TYPE_INDEX,
+                0,
+                sys::RTCFormat_RTC_FORMAT_UINT4,
+                indices,
+            )?;
+
+            sys::rtcSetGeometryBuildQuality(
+                geometry.geometry,
+                sys::RTCBuildQuality_RTC_BUILD_QUALITY_HIGH,
+            );
+            sys::rtcCommitGeometry(geometry.geometry);
         }
 
-        Self { geometry }
+        Ok(geometry)
     }
 
     /// # Safety
@@ -479,6 +2130,45 @@ impl GeometryTriangle {
     pub unsafe fn get_geometry(&self) -> sys::RTCGeometry {
         self.geometry
     }
+
+    /// Get the vertices stored in the vertex buffer of the geometry.
+    pub fn get_verts(&self) -> &[Vert] {
+        unsafe {
+            std::slice::from_raw_parts(
+                sys::rtcGetGeometryBufferData(
+                    self.geometry,
+                    sys::RTCBufferType_RTC_BUFFER_TYPE_VERTEX,
+                    0,
+                ) as *const Vert,
+                self.num_verts,
+            )
+        }
+    }
+
+    /// Get the quads stored in the index buffer of the geometry.
+    pub fn get_quads(&self) -> &[Quad] {
+        unsafe {
+            std::slice::from_raw_parts(
+                sys::rtcGetGeometryBufferData(
+                    self.geometry,
+                    sys::RTCBufferType_RTC_BUFFER_TYPE_INDEX,
+                    0,
+                ) as *const Quad,
+                self.num_quads,
+            )
+        }
+    }
+
+    /// Bounds of the vertices referenced by the quads.
+    pub fn bounds(&self) -> Aabb {
+        let verts = self.get_verts();
+        self.get_quads()
+            .iter()
+            .flat_map(|quad| [quad.i0, quad.i1, quad.i2, quad.i3])
+            .fold(Aabb::empty(), |bounds, index| {
+                bounds.grow(verts[index as usize].pos)
+            })
+    }
 }
 
 /// Sphere, stores position and radius
@@ -496,11 +2186,18 @@ impl Sphere {
     pub fn new(pos: Vec3, radius: f32) -> Self {
         Self { pos, radius }
     }
+
+    pub fn bounds(&self) -> Aabb {
+        let radius = Vec3::new(self.radius, self.radius, self.radius);
+        Aabb::new(self.pos - radius, self.pos + radius)
+    }
 }
 
 #[derive(Debug)]
 pub(crate) struct GeometrySphere {
     geometry: sys::RTCGeometry,
+    num_spheres: usize,
+    enabled: bool,
 }
 
 unsafe impl Sync for GeometrySphere {}
@@ -517,36 +2214,32 @@ impl Drop for GeometrySphere {
 }
 
 impl GeometrySphere {
-    pub(crate) fn new(device: &Device, spheres: &[Sphere]) -> Self {
-        let geometry = unsafe {
-            sys::rtcNewGeometry(
-                device.get_device(),
-                sys::RTCGeometryType_RTC_GEOMETRY_TYPE_SPHERE_POINT,
-            )
-        };
-
-        let spheres_buffer: &mut [Sphere] = unsafe {
-            std::slice::from_raw_parts_mut(
-                sys::rtcSetNewGeometryBuffer(
-                    geometry,
-                    sys::RTCBufferType_RTC_BUFFER_TYPE_VERTEX,
-                    0,
-                    sys::RTCFormat_RTC_FORMAT_FLOAT4,
-                    std::mem::size_of::<Sphere>().try_into().unwrap(),
-                    spheres.len().try_into().unwrap(),
-                ) as *mut Sphere,
-                spheres.len(),
-            )
+    pub(crate) fn new(device: &Device, spheres: &[Sphere]) -> Result<Self, Error> {
+        // released on drop if the buffer cannot be allocated
+        let geometry = Self {
+            geometry: new_geometry(device, sys::RTCGeometryType_RTC_GEOMETRY_TYPE_SPHERE_POINT)?,
+            num_spheres: spheres.len(),
+            enabled: true,
         };
 
-        spheres_buffer.copy_from_slice(spheres);
-
         unsafe {
-            sys::rtcSetGeometryBuildQuality(geometry, sys::RTCBuildQuality_RTC_BUILD_QUALITY_HIGH);
-            sys::rtcCommitGeometry(geometry);
+            set_new_geometry_buffer(
+                device,
+                geometry.geometry,
+                sys::RTCBufferType_RTC_BUFFER_TYPE_VERTEX,
+                0,
+                sys::RTCFormat_RTC_FORMAT_FLOAT4,
+                spheres,
+            )?;
+
+            sys::rtcSetGeometryBuildQuality(
+                geometry.geometry,
+                sys::RTCBuildQuality_RTC_BUILD_QUALITY_HIGH,
+            );
+            sys::rtcCommitGeometry(geometry.geometry);
         }
 
-        Self { geometry }
+        Ok(geometry)
     }
 
     /// # Safety
@@ -557,13 +2250,46 @@ impl GeometrySphere {
     pub unsafe fn get_geometry(&self) -> sys::RTCGeometry {
         self.geometry
     }
+
+    /// Get the spheres stored in the vertex buffer of the geometry.
+    pub fn get_spheres(&self) -> &[Sphere] {
+        unsafe {
+            std::slice::from_raw_parts(
+                sys::rtcGetGeometryBufferData(
+                    self.geometry,
+                    sys::RTCBufferType_RTC_BUFFER_TYPE_VERTEX,
+                    0,
+                ) as *const Sphere,
+                self.num_spheres,
+            )
+        }
+    }
+
+    pub fn bounds(&self) -> Aabb {
+        self.get_spheres()
+            .iter()
+            .fold(Aabb::empty(), |bounds, sphere| {
+                bounds.union(&sphere.bounds())
+            })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::os::raw::c_uint;
 
-    use crate::{Sphere, Triangle, Vert};
+    use crate::{
+        Aabb, Embree, Error, FaceCulling, Feature, GeometrySceneID, Quad, Ray, Sphere, Triangle,
+        Vec3, Vert,
+    };
+
+    /// Grid of 10000 small spheres at the height `z`, large enough
+    /// that building the scene takes a while and allocates memory
+    pub(crate) fn sphere_grid(z: f32) -> Vec<Sphere> {
+        (0..10000)
+            .map(|i| Sphere::new(Vec3::new((i % 100) as f32, (i / 100) as f32, z), 0.25))
+            .collect()
+    }
 
     /// [`c_uint`] should never be smaller or larger than [`u32`]
     #[test]
@@ -583,9 +2309,432 @@ mod tests {
         assert_eq!(std::mem::size_of::<Triangle>(), 4 + 4 + 4);
     }
 
+    /// [`Quad`] should never be smaller or larger
+    #[test]
+    fn quad_size_constraint() {
+        assert_eq!(std::mem::size_of::<Quad>(), 4 + 4 + 4 + 4);
+    }
+
     /// [`Sphere`] should never be smaller or larger
     #[test]
     fn sphere_size_constraint() {
         assert_eq!(std::mem::size_of::<Sphere>(), 4 + 4 + 4 + 4);
     }
+
+    /// Geometry attached to a scene must not be removable until it
+    /// is detached from the scene
+    #[test]
+    fn remove_attached_geometry() {
+        let mut embree = Embree::new();
+        let scene_id = embree.add_scene();
+        let sphere_id = embree.add_geometry_sphere(&[Sphere::new(Vec3::new(0.0, 0.0, 0.0), 1.0)]);
+
+        embree.attach_geometry_to_scene(sphere_id, scene_id);
+        assert_eq!(
+            embree.remove_geometry(sphere_id),
+            Err(Error::GeometryStillAttached)
+        );
+
+        embree
+            .detach_geometry_from_scene(sphere_id, scene_id)
+            .unwrap();
+        assert_eq!(
+            embree.detach_geometry_from_scene(sphere_id, scene_id),
+            Err(Error::GeometryNotAttached)
+        );
+        assert_eq!(embree.remove_geometry(sphere_id), Ok(()));
+        assert_eq!(
+            embree.remove_geometry(sphere_id),
+            Err(Error::GeometryNotFound)
+        );
+
+        embree.remove_scene(scene_id).unwrap();
+        assert_eq!(embree.remove_scene(scene_id), Err(Error::SceneNotFound));
+    }
+
+    /// Geometries cannot be detached from committed scenes
+    #[test]
+    fn detach_from_committed_scene() {
+        let mut embree = Embree::new();
+        let scene_id = embree.add_scene();
+        let sphere_id = embree.add_geometry_sphere(&[Sphere::new(Vec3::new(0.0, 0.0, 0.0), 1.0)]);
+        embree.attach_geometry_to_scene(sphere_id, scene_id);
+        let scene_id = embree.commit_scene(scene_id);
+
+        assert_eq!(
+            embree.detach_geometry_from_scene(sphere_id, scene_id),
+            Err(Error::SceneCommitted)
+        );
+
+        embree.remove_scene(scene_id).unwrap();
+        assert_eq!(embree.remove_geometry(sphere_id), Ok(()));
+    }
+
+    /// [`Embree::closest_hit()`] must resolve the hit geometry and
+    /// the surface data
+    #[test]
+    fn closest_hit_sphere() {
+        let mut embree = Embree::new();
+        let scene_id = embree.add_scene();
+        let sphere_id = embree.add_geometry_sphere(&[Sphere::new(Vec3::new(0.0, 0.0, 0.0), 1.0)]);
+        embree.attach_geometry_to_scene(sphere_id, scene_id);
+        let scene_id = embree.commit_scene(scene_id);
+
+        let ray = |origin| Ray::new(origin, 0.0, f32::INFINITY, Vec3::new(0.0, 0.0, 1.0), 0.0);
+
+        let hit_info = embree
+            .closest_hit(scene_id, ray(Vec3::new(0.0, 0.0, -3.0)))
+            .unwrap();
+        assert_eq!(hit_info.geometry_id, sphere_id);
+        assert_eq!(hit_info.primitive_index, 0);
+        assert!((hit_info.distance - 2.0).abs() < 1e-4);
+        assert!((hit_info.point.z + 1.0).abs() < 1e-4);
+        assert!((hit_info.normal.z + 1.0).abs() < 1e-4);
+        assert!(hit_info.instance_ids.is_empty());
+
+        assert!(embree
+            .closest_hit(scene_id, ray(Vec3::new(3.0, 0.0, -3.0)))
+            .is_none());
+    }
+
+    /// Disabled geometries must not be hit, without requiring the
+    /// scene to be rebuilt by the user
+    #[test]
+    fn set_geometry_enabled() {
+        let mut embree = Embree::new();
+        let scene_id = embree.add_scene();
+        let sphere_id = embree.add_geometry_sphere(&[Sphere::new(Vec3::new(0.0, 0.0, 0.0), 1.0)]);
+        embree.attach_geometry_to_scene(sphere_id, scene_id);
+        let scene_id = embree.commit_scene(scene_id);
+
+        let ray = || {
+            Ray::new(
+                Vec3::new(0.0, 0.0, -3.0),
+                0.0,
+                f32::INFINITY,
+                Vec3::new(0.0, 0.0, 1.0),
+                0.0,
+            )
+        };
+
+        assert!(embree.closest_hit(scene_id, ray()).is_some());
+        embree.set_geometry_enabled(sphere_id, false).unwrap();
+        assert!(embree.closest_hit(scene_id, ray()).is_none());
+        embree.set_geometry_enabled(sphere_id, true).unwrap();
+        assert!(embree.closest_hit(scene_id, ray()).is_some());
+
+        embree.remove_scene(scene_id).unwrap();
+        embree.remove_geometry(sphere_id).unwrap();
+        assert_eq!(
+            embree.set_geometry_enabled(sphere_id, false),
+            Err(Error::GeometryNotFound)
+        );
+    }
+
+    /// Rays must only hit geometries whose mask shares a bit with the
+    /// ray mask, embree compiled from source is built with ray masks
+    /// (see `build.rs`)
+    #[test]
+    fn geometry_mask() {
+        let mut embree = Embree::new();
+        let scene_id = embree.add_scene();
+        let sphere_id = embree.add_geometry_sphere(&[Sphere::new(Vec3::new(0.0, 0.0, 0.0), 1.0)]);
+        embree.attach_geometry_to_scene(sphere_id, scene_id);
+        let scene_id = embree.commit_scene(scene_id);
+
+        if !cfg!(embree_ray_mask) && !embree.device_info().ray_mask_supported {
+            assert_eq!(
+                embree.set_geometry_mask(sphere_id, 0b01),
+                Err(Error::FeatureNotSupported(Feature::RayMask))
+            );
+            return;
+        }
+        embree.set_geometry_mask(sphere_id, 0b01).unwrap();
+
+        let ray = |mask| {
+            Ray::new(
+                Vec3::new(0.0, 0.0, -3.0),
+                0.0,
+                f32::INFINITY,
+                Vec3::new(0.0, 0.0, 1.0),
+                0.0,
+            )
+            .with_mask(mask)
+        };
+
+        assert!(embree.closest_hit(scene_id, ray(0b01)).is_some());
+        assert!(embree.closest_hit(scene_id, ray(0b10)).is_none());
+
+        embree.remove_scene(scene_id).unwrap();
+        embree.remove_geometry(sphere_id).unwrap();
+        assert_eq!(
+            embree.set_geometry_mask(sphere_id, 0b01),
+            Err(Error::GeometryNotFound)
+        );
+    }
+
+    /// Scene bounds must contain all the attached geometries
+    #[test]
+    fn scene_and_geometry_bounds() {
+        let mut embree = Embree::new();
+        let scene_id = embree.add_scene();
+        let sphere_id = embree.add_geometry_sphere(&[
+            Sphere::new(Vec3::new(0.0, 0.0, 0.0), 1.0),
+            Sphere::new(Vec3::new(2.0, 0.0, 0.0), 0.5),
+        ]);
+        let triangle_id = embree.add_geometry_triangle(
+            &[
+                Vert::new(Vec3::new(0.0, 0.0, 0.0)),
+                Vert::new(Vec3::new(0.0, 3.0, 0.0)),
+                Vert::new(Vec3::new(0.0, 0.0, 4.0)),
+            ],
+            &[Triangle::new(0, 1, 2)],
+        );
+        embree.attach_geometry_to_scene(sphere_id, scene_id);
+        embree.attach_geometry_to_scene(triangle_id, scene_id);
+        let scene_id = embree.commit_scene(scene_id);
+
+        assert_eq!(
+            embree.geometry_bounds(sphere_id),
+            Aabb::new(Vec3::new(-1.0, -1.0, -1.0), Vec3::new(2.5, 1.0, 1.0))
+        );
+        assert_eq!(
+            embree.geometry_bounds(triangle_id),
+            Aabb::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 3.0, 4.0))
+        );
+
+        let expected = Aabb::new(Vec3::new(-1.0, -1.0, -1.0), Vec3::new(2.5, 3.0, 4.0));
+        let bounds = embree.scene_bounds(scene_id);
+        assert!((bounds.min - expected.min).length() < 1e-4);
+        assert!((bounds.max - expected.max).length() < 1e-4);
+    }
+
+    /// The same geometry attached to multiple scenes must be
+    /// resolvable from the [`GeometrySceneID`] of each scene
+    #[test]
+    fn attach_geometry_to_multiple_scenes() {
+        let mut embree = Embree::new();
+        let scene_1_id = embree.add_scene();
+        let scene_2_id = embree.add_scene();
+        let sphere_1_id = embree.add_geometry_sphere(&[Sphere::new(Vec3::new(0.0, 0.0, 0.0), 1.0)]);
+        let sphere_2_id = embree.add_geometry_sphere(&[Sphere::new(Vec3::new(0.0, 0.0, 0.0), 1.0)]);
+
+        embree.attach_geometry_to_scene(sphere_1_id, scene_1_id);
+        embree.attach_geometry_to_scene(sphere_2_id, scene_2_id);
+        embree.attach_geometry_to_scene(sphere_1_id, scene_2_id);
+
+        let scene_1_id = embree.commit_scene(scene_1_id);
+        let scene_2_id = embree.commit_scene(scene_2_id);
+
+        assert_eq!(
+            embree.get_geometry_id_from_geometry_scene_id(scene_1_id, &GeometrySceneID(0)),
+            Some(&sphere_1_id)
+        );
+        assert_eq!(
+            embree.get_geometry_id_from_geometry_scene_id(scene_2_id, &GeometrySceneID(0)),
+            Some(&sphere_2_id)
+        );
+        assert_eq!(
+            embree.get_geometry_id_from_geometry_scene_id(scene_2_id, &GeometrySceneID(1)),
+            Some(&sphere_1_id)
+        );
+    }
+
+    /// A commit cancelled by the progress monitor must leave the
+    /// scene uncommitted so it can be committed again
+    #[test]
+    fn commit_scene_with_progress() {
+        let mut embree = Embree::new();
+        let scene_id = embree.add_scene();
+        let spheres = sphere_grid(0.0);
+        let sphere_id = embree.add_geometry_sphere(&spheres);
+        embree.attach_geometry_to_scene(sphere_id, scene_id);
+
+        assert_eq!(
+            embree.commit_scene_with_progress(scene_id, |_| false),
+            Err(Error::CommitCancelled)
+        );
+
+        let mut progress = Vec::new();
+        let scene_id = embree
+            .commit_scene_with_progress(scene_id, |n| {
+                progress.push(n);
+                true
+            })
+            .unwrap();
+        assert!(progress.iter().all(|n| (0.0..=1.0).contains(n)));
+
+        assert!(embree
+            .closest_hit(
+                scene_id,
+                Ray::new(
+                    Vec3::new(0.0, 0.0, 5.0),
+                    0.0,
+                    f32::INFINITY,
+                    Vec3::new(0.0, 0.0, -1.0),
+                    0.0
+                )
+            )
+            .is_some());
+
+        embree.remove_scene(scene_id).unwrap();
+        assert_eq!(
+            embree.commit_scene_with_progress(scene_id, |_| true),
+            Err(Error::SceneNotFound)
+        );
+    }
+
+    /// All the threads joining the commit must end up with a built
+    /// scene
+    #[test]
+    fn join_commit_scene() {
+        let mut embree = Embree::new();
+        let scene_id = embree.add_scene();
+        let spheres = sphere_grid(0.0);
+        let sphere_id = embree.add_geometry_sphere(&spheres);
+        embree.attach_geometry_to_scene(sphere_id, scene_id);
+
+        let commit = embree.join_commit_scene(scene_id);
+        std::thread::scope(|scope| {
+            (0..4).for_each(|_| {
+                scope.spawn(|| commit.join());
+            });
+            commit.join();
+        });
+        let scene_id = commit.finish().unwrap();
+
+        assert!(embree
+            .closest_hit(
+                scene_id,
+                Ray::new(
+                    Vec3::new(0.0, 0.0, 5.0),
+                    0.0,
+                    f32::INFINITY,
+                    Vec3::new(0.0, 0.0, -1.0),
+                    0.0
+                )
+            )
+            .is_some());
+    }
+
+    /// The previously committed scene must stay queryable while the
+    /// new version is committed on a background thread
+    #[test]
+    fn commit_scene_async() {
+        let mut embree = Embree::new();
+        let ray = || {
+            Ray::new(
+                Vec3::new(0.0, 0.0, 5.0),
+                0.0,
+                f32::INFINITY,
+                Vec3::new(0.0, 0.0, -1.0),
+                0.0,
+            )
+        };
+
+        let previous_scene_id = embree.add_scene();
+        let sphere_id = embree.add_geometry_sphere(&[Sphere::new(Vec3::new(0.0, 0.0, 0.0), 1.0)]);
+        embree.attach_geometry_to_scene(sphere_id, previous_scene_id);
+        let previous_scene_id = embree.commit_scene(previous_scene_id);
+
+        let scene_id = embree.add_scene();
+        let spheres = sphere_grid(2.0);
+        let spheres_id = embree.add_geometry_sphere(&spheres);
+        embree.attach_geometry_to_scene(spheres_id, scene_id);
+
+        let handle = embree.commit_scene_async(scene_id);
+        assert_eq!(
+            embree
+                .closest_hit(previous_scene_id, ray())
+                .unwrap()
+                .geometry_id,
+            sphere_id
+        );
+        assert_eq!(
+            embree.remove_geometry(spheres_id),
+            Err(Error::GeometryStillAttached)
+        );
+        assert_eq!(
+            embree.detach_geometry_from_scene(spheres_id, scene_id),
+            Err(Error::SceneCommitting)
+        );
+        assert_eq!(embree.remove_scene(scene_id), Err(Error::SceneCommitting));
+
+        let scene_id = embree.finish_commit(handle).unwrap();
+        assert_eq!(
+            embree.closest_hit(scene_id, ray()).unwrap().geometry_id,
+            spheres_id
+        );
+        embree.remove_scene(previous_scene_id).unwrap();
+    }
+
+    /// Dropping the handle of a background commit must not keep the
+    /// scene and its geometries locked once the commit is done
+    #[test]
+    fn drop_commit_handle() {
+        let mut embree = Embree::new();
+        let scene_id = embree.add_scene();
+        let spheres_id = embree.add_geometry_sphere(&sphere_grid(0.0));
+        embree.attach_geometry_to_scene(spheres_id, scene_id);
+
+        drop(embree.commit_scene_async(scene_id));
+        while embree.remove_scene(scene_id) == Err(Error::SceneCommitting) {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+
+        assert_eq!(embree.remove_geometry(spheres_id), Ok(()));
+    }
+
+    /// Back facing hits must be skipped only when asked for
+    #[test]
+    fn closest_hit_with_backface_culling() {
+        let mut embree = Embree::new();
+        let scene_id = embree.add_scene();
+        let triangle_id = embree.add_geometry_triangle(
+            &[
+                Vert::new(Vec3::new(0.0, 0.0, 0.0)),
+                Vert::new(Vec3::new(1.0, 0.0, 0.0)),
+                Vert::new(Vec3::new(0.0, 1.0, 0.0)),
+            ],
+            &[Triangle::new(0, 1, 2)],
+        );
+        embree.attach_geometry_to_scene(triangle_id, scene_id);
+        let scene_id = embree.commit_scene(scene_id);
+
+        let ray = |z: f32| {
+            Ray::new(
+                Vec3::new(0.25, 0.25, z),
+                0.0,
+                f32::INFINITY,
+                Vec3::new(0.0, 0.0, -z.signum()),
+                0.0,
+            )
+        };
+        let normal = embree
+            .closest_hit(scene_id, ray(1.0))
+            .or_else(|| embree.closest_hit(scene_id, ray(-1.0)))
+            .unwrap()
+            .normal;
+        // the side of the triangle that the normal points to is the
+        // front side
+        let (front, back) = if normal.z > 0.0 {
+            (1.0, -1.0)
+        } else {
+            (-1.0, 1.0)
+        };
+
+        assert!(embree
+            .closest_hit_with_culling(scene_id, ray(front), FaceCulling::BackFaces)
+            .is_some());
+        assert!(embree
+            .closest_hit_with_culling(scene_id, ray(back), FaceCulling::BackFaces)
+            .is_none());
+        // unless embree itself culls the back faces
+        assert_eq!(
+            embree
+                .closest_hit_with_culling(scene_id, ray(back), FaceCulling::None)
+                .is_some(),
+            !embree.device_info().backface_culling_enabled
+        );
+    }
 }
@@ -0,0 +1,205 @@
+use std::os::raw::c_void;
+use std::sync::atomic::{AtomicIsize, AtomicUsize, Ordering};
+
+use crate::{Embree, Error, Scene, SceneID};
+
+/// Memory allocated by embree for the device, see
+/// [`Embree::memory_usage()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// Bytes currently allocated.
+    pub current: usize,
+    /// Maximum bytes allocated at any point so far.
+    pub peak: usize,
+    /// Hard limit on the bytes allocated, see
+    /// [`Embree::set_memory_limit()`].
+    pub limit: Option<usize>,
+}
+
+/// User data of [`memory_monitor_func()`], tracks the memory
+/// allocated by the device
+#[derive(Debug)]
+pub(crate) struct MemoryMonitor {
+    current: AtomicIsize,
+    peak: AtomicIsize,
+    /// `usize::MAX` if there is no limit
+    limit: AtomicUsize,
+}
+
+impl MemoryMonitor {
+    pub(crate) fn new() -> Self {
+        Self {
+            current: AtomicIsize::new(0),
+            peak: AtomicIsize::new(0),
+            limit: AtomicUsize::new(usize::MAX),
+        }
+    }
+
+    pub(crate) fn usage(&self) -> MemoryUsage {
+        let limit = self.limit.load(Ordering::SeqCst);
+        MemoryUsage {
+            current: self.current.load(Ordering::SeqCst).max(0) as usize,
+            peak: self.peak.load(Ordering::SeqCst).max(0) as usize,
+            limit: (limit != usize::MAX).then_some(limit),
+        }
+    }
+
+    pub(crate) fn set_limit(&self, limit: Option<usize>) {
+        self.limit
+            .store(limit.unwrap_or(usize::MAX), Ordering::SeqCst);
+    }
+}
+
+/// Called by embree before (`post` is false) memory is allocated and
+/// after (`post` is true) memory is freed, `bytes` is negative for
+/// the latter. Returning false makes the allocation fail with
+/// `RTC_ERROR_OUT_OF_MEMORY`.
+pub(crate) unsafe extern "C" fn memory_monitor_func(
+    ptr: *mut c_void,
+    bytes: isize,
+    post: bool,
+) -> bool {
+    let monitor = &*(ptr as *const MemoryMonitor);
+
+    if bytes > 0 && !post {
+        let limit = monitor.limit.load(Ordering::SeqCst);
+        let current = monitor.current.fetch_add(bytes, Ordering::SeqCst) + bytes;
+        if current.max(0) as usize > limit {
+            // the allocation does not happen, so it is not tracked
+            monitor.current.fetch_sub(bytes, Ordering::SeqCst);
+            return false;
+        }
+        monitor.peak.fetch_max(current, Ordering::SeqCst);
+    } else {
+        monitor.current.fetch_add(bytes, Ordering::SeqCst);
+    }
+
+    true
+}
+
+impl Embree {
+    /// Memory allocated by embree so far for all the scenes and
+    /// geometries.
+    ///
+    /// The memory monitor of embree is only given the device and the
+    /// number of bytes, not the scene that allocates them, see
+    /// [`Embree::scene_memory_usage()`] for what can be attributed to
+    /// a scene.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        self.device.memory_monitor.usage()
+    }
+
+    /// Bytes allocated by embree while the scene of the given id was
+    /// committed, mostly its BVH.
+    ///
+    /// [`None`] if the allocations cannot be attributed to the scene:
+    /// the scene was committed by [`Embree::commit_scene_async()`] or
+    /// while another scene was being committed that way, or it was
+    /// committed again by [`Embree::set_geometry_enabled()`] or
+    /// [`Embree::set_geometry_mask()`]. The memory of the attached
+    /// geometries is allocated when they are added, so it is not
+    /// included.
+    ///
+    /// Returns [`Error::SceneNotFound`], [`Error::SceneNotCommitted`]
+    /// or [`Error::SceneCommitting`] if the scene is not committed.
+    pub fn scene_memory_usage(&self, scene_id: SceneID) -> Result<Option<usize>, Error> {
+        Ok(self.get_committed_scene(scene_id)?.memory_usage)
+    }
+
+    /// Current memory usage before a synchronous commit, [`None`] if
+    /// a scene is being committed on a background thread, whose
+    /// allocations cannot be told apart.
+    pub(crate) fn commit_memory_start(&self) -> Option<usize> {
+        let committing = self
+            .scenes
+            .iter()
+            .any(|(_, scene)| matches!(scene, Scene::Committing(_)));
+
+        (!committing).then(|| self.memory_usage().current)
+    }
+
+    /// Growth of the memory usage since [`Self::commit_memory_start()`].
+    pub(crate) fn commit_memory_growth(&self, start: Option<usize>) -> Option<usize> {
+        start.map(|start| self.memory_usage().current.saturating_sub(start))
+    }
+
+    /// Set a hard limit on the bytes embree can allocate, [`None`]
+    /// removes the limit.
+    ///
+    /// Allocations that would exceed the limit fail,
+    /// [`Embree::try_commit_scene()`], [`Embree::try_add_scene()`]
+    /// and the `try_add_geometry_*` functions such as
+    /// [`Embree::try_add_geometry_triangle()`] then return
+    /// [`crate::Error::MemoryLimitExceeded`] instead of the process
+    /// running out of memory.
+    pub fn set_memory_limit(&self, limit: Option<usize>) {
+        self.device.memory_monitor.set_limit(limit);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::sphere_grid;
+    use crate::{Embree, Error};
+
+    /// Commits exceeding the memory limit must fail and the memory
+    /// usage must be tracked
+    #[test]
+    fn memory_limit() {
+        let mut embree = Embree::new();
+        let spheres = sphere_grid(0.0);
+        let sphere_id = embree.add_geometry_sphere(&spheres);
+        let scene_id = embree.add_scene();
+        embree.attach_geometry_to_scene(sphere_id, scene_id);
+        let usage = embree.memory_usage();
+        assert!(usage.current > 0);
+        assert_eq!(usage.limit, None);
+
+        embree.set_memory_limit(Some(usage.current));
+        assert_eq!(
+            embree.try_commit_scene(scene_id),
+            Err(Error::MemoryLimitExceeded)
+        );
+        assert_eq!(
+            embree.try_add_geometry_sphere(&spheres),
+            Err(Error::MemoryLimitExceeded)
+        );
+
+        embree.set_memory_limit(None);
+        let scene_id = embree.try_commit_scene(scene_id).unwrap();
+        let usage = embree.memory_usage();
+        assert!(usage.current > 0);
+        assert!(usage.peak >= usage.current);
+
+        embree.remove_scene(scene_id).unwrap();
+    }
+
+    /// Synchronous commits must report the memory they allocated,
+    /// background commits cannot
+    #[test]
+    fn scene_memory_usage() {
+        let mut embree = Embree::new();
+        let sphere_id = embree.add_geometry_sphere(&sphere_grid(0.0));
+        let scene_id = embree.add_scene();
+        embree.attach_geometry_to_scene(sphere_id, scene_id);
+        assert_eq!(
+            embree.scene_memory_usage(scene_id),
+            Err(Error::SceneNotCommitted)
+        );
+
+        let current = embree.memory_usage().current;
+        let scene_id = embree.commit_scene(scene_id);
+        let memory_usage = embree.scene_memory_usage(scene_id).unwrap().unwrap();
+        assert!(memory_usage > 0);
+        assert_eq!(embree.memory_usage().current, current + memory_usage);
+
+        let async_scene_id = embree.add_scene();
+        embree.attach_geometry_to_scene(sphere_id, async_scene_id);
+        let handle = embree.commit_scene_async(async_scene_id);
+        let async_scene_id = embree.finish_commit(handle).unwrap();
+        assert_eq!(embree.scene_memory_usage(async_scene_id), Ok(None));
+
+        embree.set_geometry_enabled(sphere_id, false).unwrap();
+        assert_eq!(embree.scene_memory_usage(scene_id), Ok(None));
+    }
+}
@@ -0,0 +1,560 @@
+use std::os::raw::c_void;
+
+use generational_arena::Arena;
+
+use crate::{
+    sys, Embree, Error, Geometry, GeometryID, GeometrySceneID, PointQuery, PointQueryContext,
+    SceneCommitted, SceneID, Vec3,
+};
+
+/// Closest point on the surface of a scene to the query point
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClosestPoint {
+    /// Distance from the query point to the closest point.
+    pub distance: f32,
+    /// Closest point on the surface in world space.
+    pub point: Vec3,
+    /// Geometry that the closest point lies on.
+    pub geometry_id: GeometryID,
+    /// Index of the primitive (triangle, sphere, etc.) of the
+    /// geometry that the closest point lies on.
+    pub primitive_index: u32,
+    /// Barycentric coordinates of the closest point within the
+    /// primitive, always zero for spheres.
+    pub uv: (f32, f32),
+}
+
+/// Reference to a primitive (triangle, sphere, etc.) of a geometry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PrimRef {
+    pub geometry_id: GeometryID,
+    pub primitive_index: u32,
+}
+
+impl Embree {
+    /// Find the closest point on the surface of the scene of the
+    /// given id to the given point.
+    ///
+    /// Only the surface within `radius` of the point is considered
+    /// if a radius is provided, returns [`None`] if there is no
+    /// surface within the radius.
+    ///
+    /// Returns [`Error::SceneNotFound`], [`Error::SceneNotCommitted`]
+    /// or [`Error::SceneCommitting`] if the scene cannot be queried.
+    pub fn closest_point(
+        &self,
+        scene_id: SceneID,
+        point: Vec3,
+        radius: Option<f32>,
+    ) -> Result<Option<ClosestPoint>, Error> {
+        let scene = self.get_committed_scene(scene_id)?;
+
+        let mut data = ClosestPointQueryData {
+            scene,
+            geometries: &self.geometries,
+            closest_point: None,
+        };
+
+        let mut query = PointQuery {
+            x: point.x,
+            y: point.y,
+            z: point.z,
+            time: 0.0,
+            radius: radius.unwrap_or(f32::INFINITY),
+        };
+
+        let mut context = PointQueryContext::default();
+
+        unsafe {
+            sys::rtcPointQuery(
+                scene.get_scene(),
+                &mut query,
+                &mut context,
+                Some(closest_point_query_func),
+                &mut data as *mut ClosestPointQueryData as *mut c_void,
+            );
+        }
+
+        Ok(data.closest_point)
+    }
+
+    /// Find all the primitives of the scene of the given id whose
+    /// surface lies within `radius` of `center`.
+    ///
+    /// The returned primitives are sorted and contain no duplicates.
+    /// Fails like [`Self::closest_point()`] if the scene cannot be
+    /// queried.
+    pub fn range_query(
+        &self,
+        scene_id: SceneID,
+        center: Vec3,
+        radius: f32,
+    ) -> Result<Vec<PrimRef>, Error> {
+        let scene = self.get_committed_scene(scene_id)?;
+
+        let mut data = RangeQueryData {
+            scene,
+            geometries: &self.geometries,
+            prim_refs: Vec::new(),
+        };
+
+        let mut query = PointQuery {
+            x: center.x,
+            y: center.y,
+            z: center.z,
+            time: 0.0,
+            radius,
+        };
+
+        let mut context = PointQueryContext::default();
+
+        unsafe {
+            sys::rtcPointQuery(
+                scene.get_scene(),
+                &mut query,
+                &mut context,
+                Some(range_query_func),
+                &mut data as *mut RangeQueryData as *mut c_void,
+            );
+        }
+
+        // a primitive can be referenced by multiple leaves of the BVH
+        // (spatial splits)
+        data.prim_refs.sort_unstable();
+        data.prim_refs.dedup();
+
+        Ok(data.prim_refs)
+    }
+}
+
+/// Data passed to [`closest_point_query_func()`] through the user
+/// pointer of the point query.
+struct ClosestPointQueryData<'a> {
+    scene: &'a SceneCommitted,
+    geometries: &'a Arena<Geometry>,
+    closest_point: Option<ClosestPoint>,
+}
+
+/// Point query function called by embree for every primitive that
+/// may lie within the query radius.
+///
+/// Shrinks the query radius to the distance of the closest point
+/// found so far, so embree can cull the rest of the BVH.
+unsafe extern "C" fn closest_point_query_func(
+    args: *mut sys::RTCPointQueryFunctionArguments,
+) -> bool {
+    let args = &mut *args;
+    let data = &mut *(args.userPtr as *mut ClosestPointQueryData);
+    let query = &mut *args.query;
+
+    let query_point = Vec3::new(query.x, query.y, query.z);
+
+    let geometry_id = *data
+        .scene
+        .attached_geometries
+        .get(&GeometrySceneID(args.geomID))
+        .expect("geometry must be attached to the scene");
+
+    let (point, uv) = data
+        .geometries
+        .get(geometry_id.0)
+        .expect("geometry of given id is not available")
+        .closest_point_on_primitive(args.primID, query_point);
+
+    let distance = (point - query_point).length();
+
+    if distance < query.radius {
+        query.radius = distance;
+        data.closest_point = Some(ClosestPoint {
+            distance,
+            point,
+            geometry_id,
+            primitive_index: args.primID,
+            uv,
+        });
+        true
+    } else {
+        false
+    }
+}
+
+/// Data passed to [`range_query_func()`] through the user pointer of
+/// the point query.
+struct RangeQueryData<'a> {
+    scene: &'a SceneCommitted,
+    geometries: &'a Arena<Geometry>,
+    prim_refs: Vec<PrimRef>,
+}
+
+/// Point query function called by embree for every primitive whose
+/// bounds overlap the query sphere.
+///
+/// The query radius is never changed since all the primitives
+/// within the radius are required.
+unsafe extern "C" fn range_query_func(args: *mut sys::RTCPointQueryFunctionArguments) -> bool {
+    let args = &mut *args;
+    let data = &mut *(args.userPtr as *mut RangeQueryData);
+    let query = &*args.query;
+
+    let query_point = Vec3::new(query.x, query.y, query.z);
+
+    let geometry_id = *data
+        .scene
+        .attached_geometries
+        .get(&GeometrySceneID(args.geomID))
+        .expect("geometry must be attached to the scene");
+
+    let (point, _) = data
+        .geometries
+        .get(geometry_id.0)
+        .expect("geometry of given id is not available")
+        .closest_point_on_primitive(args.primID, query_point);
+
+    if (point - query_point).length() <= query.radius {
+        data.prim_refs.push(PrimRef {
+            geometry_id,
+            primitive_index: args.primID,
+        });
+    }
+
+    false
+}
+
+impl Geometry {
+    /// Closest point on the surface of the primitive of the given
+    /// index to the given point, along with the barycentric
+    /// coordinates of the closest point within the primitive.
+    pub(crate) fn closest_point_on_primitive(
+        &self,
+        primitive_index: u32,
+        point: Vec3,
+    ) -> (Vec3, (f32, f32)) {
+        match self {
+            Geometry::Triangle(geometry) => {
+                let verts = geometry.get_verts();
+                let triangle = geometry.get_triangles()[primitive_index as usize];
+                closest_point_on_triangle(
+                    point,
+                    verts[triangle.i0 as usize].pos,
+                    verts[triangle.i1 as usize].pos,
+                    verts[triangle.i2 as usize].pos,
+                )
+            }
+            Geometry::Quad(geometry) => {
+                let verts = geometry.get_verts();
+                let quad = geometry.get_quads()[primitive_index as usize];
+                closest_point_on_quad(
+                    point,
+                    [
+                        verts[quad.i0 as usize].pos,
+                        verts[quad.i1 as usize].pos,
+                        verts[quad.i2 as usize].pos,
+                        verts[quad.i3 as usize].pos,
+                    ],
+                )
+            }
+            Geometry::Sphere(geometry) => {
+                let sphere = geometry.get_spheres()[primitive_index as usize];
+                (
+                    closest_point_on_sphere(point, sphere.pos, sphere.radius),
+                    (0.0, 0.0),
+                )
+            }
+        }
+    }
+}
+
+/// Closest point on the triangle `a`, `b`, `c` to the point `p`.
+///
+/// Also returns the barycentric coordinates `(u, v)` of the closest
+/// point such that it is `(1 - u - v) * a + u * b + v * c`, same as
+/// the hit barycentric coordinates given by embree.
+///
+/// Reference: Real-Time Collision Detection by Christer Ericson,
+/// section 5.1.5.
+pub(crate) fn closest_point_on_triangle(p: Vec3, a: Vec3, b: Vec3, c: Vec3) -> (Vec3, (f32, f32)) {
+    let ab = b - a;
+    let ac = c - a;
+
+    // vertex region of a
+    let ap = p - a;
+    let d1 = ab.dot(&ap);
+    let d2 = ac.dot(&ap);
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return (a, (0.0, 0.0));
+    }
+
+    // vertex region of b
+    let bp = p - b;
+    let d3 = ab.dot(&bp);
+    let d4 = ac.dot(&bp);
+    if d3 >= 0.0 && d4 <= d3 {
+        return (b, (1.0, 0.0));
+    }
+
+    // edge region of ab
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        let v = d1 / (d1 - d3);
+        return (a + ab * v, (v, 0.0));
+    }
+
+    // vertex region of c
+    let cp = p - c;
+    let d5 = ab.dot(&cp);
+    let d6 = ac.dot(&cp);
+    if d6 >= 0.0 && d5 <= d6 {
+        return (c, (0.0, 1.0));
+    }
+
+    // edge region of ac
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        let w = d2 / (d2 - d6);
+        return (a + ac * w, (0.0, w));
+    }
+
+    // edge region of bc
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+        let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return (b + (c - b) * w, (1.0 - w, w));
+    }
+
+    // face region
+    let denom = 1.0 / (va + vb + vc);
+    let v = vb * denom;
+    let w = vc * denom;
+    (a + ab * v + ac * w, (v, w))
+}
+
+/// Triangles that embree splits the quad `v0, v1, v2, v3` into,
+/// `v0, v1, v3` and `v2, v3, v1`.
+pub(crate) fn quad_triangles(quad: &[Vec3; 4]) -> [[Vec3; 3]; 2] {
+    let [v0, v1, v2, v3] = *quad;
+    [[v0, v1, v3], [v2, v3, v1]]
+}
+
+/// Closest point on the quad `v0, v1, v2, v3` to the point `p`,
+/// the closer of the closest points on the two triangles of the
+/// quad, see [`quad_triangles()`].
+///
+/// Also returns the coordinates `(u, v)` of the closest point within
+/// the quad, same as the hit coordinates given by embree, where
+/// `(0, 0)` is `v0`, `(1, 0)` is `v1`, `(1, 1)` is `v2` and `(0, 1)`
+/// is `v3`.
+pub(crate) fn closest_point_on_quad(p: Vec3, quad: [Vec3; 4]) -> (Vec3, (f32, f32)) {
+    let [t0, t1] = quad_triangles(&quad);
+    let (point_0, uv_0) = closest_point_on_triangle(p, t0[0], t0[1], t0[2]);
+    let (point_1, (s, t)) = closest_point_on_triangle(p, t1[0], t1[1], t1[2]);
+
+    if (point_0 - p).length() <= (point_1 - p).length() {
+        (point_0, uv_0)
+    } else {
+        // the second triangle starts at v2, which is (1, 1) in the
+        // quad
+        (point_1, (1.0 - s, 1.0 - t))
+    }
+}
+
+/// Closest point on the surface of the sphere to the point `p`.
+pub(crate) fn closest_point_on_sphere(p: Vec3, center: Vec3, radius: f32) -> Vec3 {
+    let direction = p - center;
+    if direction.length() == 0.0 {
+        // every point on the surface is equally close, pick any
+        return center + Vec3::new(radius, 0.0, 0.0);
+    }
+    center + direction.normalize() * radius
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        closest_point_on_quad, closest_point_on_sphere, closest_point_on_triangle, PrimRef,
+    };
+    use crate::{Embree, Error, Quad, Sphere, Triangle, Vec3, Vert};
+
+    /// Closest point must lie in the correct region of the triangle
+    #[test]
+    fn closest_point_on_triangle_regions() {
+        let a = Vec3::new(0.0, 0.0, 0.0);
+        let b = Vec3::new(1.0, 0.0, 0.0);
+        let c = Vec3::new(0.0, 1.0, 0.0);
+
+        // face
+        let (point, uv) = closest_point_on_triangle(Vec3::new(0.25, 0.25, 1.0), a, b, c);
+        assert_eq!(point, Vec3::new(0.25, 0.25, 0.0));
+        assert_eq!(uv, (0.25, 0.25));
+
+        // vertices
+        assert_eq!(
+            closest_point_on_triangle(Vec3::new(-1.0, -1.0, 0.0), a, b, c),
+            (a, (0.0, 0.0))
+        );
+        assert_eq!(
+            closest_point_on_triangle(Vec3::new(2.0, -1.0, 0.0), a, b, c),
+            (b, (1.0, 0.0))
+        );
+        assert_eq!(
+            closest_point_on_triangle(Vec3::new(-1.0, 2.0, 0.0), a, b, c),
+            (c, (0.0, 1.0))
+        );
+
+        // edge bc
+        let (point, uv) = closest_point_on_triangle(Vec3::new(1.0, 1.0, 0.0), a, b, c);
+        assert_eq!(point, Vec3::new(0.5, 0.5, 0.0));
+        assert_eq!(uv, (0.5, 0.5));
+    }
+
+    /// Closest point must be found on both the triangles of the quad
+    /// with the coordinates of the quad
+    #[test]
+    fn closest_point_on_quad_triangles() {
+        let quad = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        ];
+
+        // first triangle
+        let (point, uv) = closest_point_on_quad(Vec3::new(0.25, 0.25, 1.0), quad);
+        assert_eq!(point, Vec3::new(0.25, 0.25, 0.0));
+        assert_eq!(uv, (0.25, 0.25));
+
+        // second triangle
+        let (point, uv) = closest_point_on_quad(Vec3::new(0.75, 0.5, 1.0), quad);
+        assert_eq!(point, Vec3::new(0.75, 0.5, 0.0));
+        assert_eq!(uv, (0.75, 0.5));
+
+        // vertex v2
+        assert_eq!(
+            closest_point_on_quad(Vec3::new(2.0, 2.0, 0.0), quad),
+            (quad[2], (1.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn closest_point_on_sphere_surface() {
+        assert_eq!(
+            closest_point_on_sphere(Vec3::new(0.0, 3.0, 0.0), Vec3::new(0.0, 1.0, 0.0), 1.0),
+            Vec3::new(0.0, 2.0, 0.0)
+        );
+    }
+
+    /// [`Embree::closest_point()`] must find the closest geometry and
+    /// respect the query radius
+    #[test]
+    fn closest_point_scene() {
+        let mut embree = Embree::new();
+        let scene_id = embree.add_scene();
+        let sphere_id = embree.add_geometry_sphere(&[Sphere::new(Vec3::new(0.0, 0.0, 5.0), 1.0)]);
+        let triangle_id = embree.add_geometry_triangle(
+            &[
+                Vert::new(Vec3::new(-1.0, -1.0, 0.0)),
+                Vert::new(Vec3::new(1.0, -1.0, 0.0)),
+                Vert::new(Vec3::new(0.0, 1.0, 0.0)),
+            ],
+            &[Triangle::new(0, 1, 2)],
+        );
+        embree.attach_geometry_to_scene(sphere_id, scene_id);
+        embree.attach_geometry_to_scene(triangle_id, scene_id);
+        let scene_id = embree.commit_scene(scene_id);
+
+        let closest_point = embree
+            .closest_point(scene_id, Vec3::new(0.0, 0.0, 1.0), None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(closest_point.geometry_id, triangle_id);
+        assert_eq!(closest_point.primitive_index, 0);
+        assert!((closest_point.distance - 1.0).abs() < 1e-5);
+
+        let closest_point = embree
+            .closest_point(scene_id, Vec3::new(0.0, 0.0, 3.5), None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(closest_point.geometry_id, sphere_id);
+        assert!((closest_point.point.z - 4.0).abs() < 1e-5);
+
+        assert!(embree
+            .closest_point(scene_id, Vec3::new(0.0, 0.0, 2.5), Some(1.0))
+            .unwrap()
+            .is_none());
+
+        let uncommitted_scene_id = embree.add_scene();
+        assert_eq!(
+            embree.closest_point(uncommitted_scene_id, Vec3::new(0.0, 0.0, 0.0), None),
+            Err(Error::SceneNotCommitted)
+        );
+    }
+
+    /// [`Embree::closest_point()`] must find the closest point on
+    /// quads
+    #[test]
+    fn closest_point_scene_quad() {
+        let mut embree = Embree::new();
+        let scene_id = embree.add_scene();
+        let quad_id = embree.add_geometry_quad(
+            &[
+                Vert::new(Vec3::new(-1.0, -1.0, 0.0)),
+                Vert::new(Vec3::new(1.0, -1.0, 0.0)),
+                Vert::new(Vec3::new(1.0, 1.0, 0.0)),
+                Vert::new(Vec3::new(-1.0, 1.0, 0.0)),
+            ],
+            &[Quad::new(0, 1, 2, 3)],
+        );
+        embree.attach_geometry_to_scene(quad_id, scene_id);
+        let scene_id = embree.commit_scene(scene_id);
+
+        let closest_point = embree
+            .closest_point(scene_id, Vec3::new(0.5, 0.5, 2.0), None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(closest_point.geometry_id, quad_id);
+        assert_eq!(closest_point.primitive_index, 0);
+        assert!((closest_point.distance - 2.0).abs() < 1e-5);
+        assert!((closest_point.uv.0 - 0.75).abs() < 1e-5);
+        assert!((closest_point.uv.1 - 0.75).abs() < 1e-5);
+    }
+
+    /// [`Embree::range_query()`] must find exactly the primitives
+    /// whose surface is within the radius
+    #[test]
+    fn range_query_scene() {
+        let mut embree = Embree::new();
+        let scene_id = embree.add_scene();
+        let sphere_id = embree.add_geometry_sphere(&[
+            Sphere::new(Vec3::new(0.0, 0.0, 0.0), 1.0),
+            Sphere::new(Vec3::new(3.0, 0.0, 0.0), 1.0),
+            Sphere::new(Vec3::new(0.0, 3.0, 0.0), 1.0),
+        ]);
+        embree.attach_geometry_to_scene(sphere_id, scene_id);
+        let scene_id = embree.commit_scene(scene_id);
+
+        assert_eq!(
+            embree
+                .range_query(scene_id, Vec3::new(1.5, 0.0, 0.0), 0.75)
+                .unwrap(),
+            vec![
+                PrimRef {
+                    geometry_id: sphere_id,
+                    primitive_index: 0,
+                },
+                PrimRef {
+                    geometry_id: sphere_id,
+                    primitive_index: 1,
+                },
+            ]
+        );
+        assert!(embree
+            .range_query(scene_id, Vec3::new(10.0, 0.0, 0.0), 1.0)
+            .unwrap()
+            .is_empty());
+
+        embree.remove_scene(scene_id).unwrap();
+        assert_eq!(
+            embree.range_query(scene_id, Vec3::new(0.0, 0.0, 0.0), 1.0),
+            Err(Error::SceneNotFound)
+        );
+    }
+}
@@ -4,7 +4,43 @@
 
 use std::os::raw::c_uint;
 
+// bindings are generated by `build.rs` only with the `bindgen`
+// feature, which requires libclang, the pregenerated ones are used
+// otherwise
+#[cfg(feature = "bindgen")]
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+#[cfg(all(not(feature = "bindgen"), not(feature = "embree4")))]
+include!("bindings/embree3.rs");
+#[cfg(all(not(feature = "bindgen"), feature = "embree4"))]
+include!("bindings/embree4.rs");
 
 // must keep consistent with RTC_INVALID_GEOMETRY_ID
 pub const RTC_INVALID_GEOMETRY_ID: c_uint = c_uint::MAX;
+
+#[cfg(all(
+    test,
+    feature = "bindgen",
+    not(any(
+        feature = "max-instance-level-2",
+        feature = "max-instance-level-4",
+        feature = "max-instance-level-8"
+    ))
+))]
+mod tests {
+    /// The pregenerated bindings must match the ones generated from
+    /// the headers, build with `EMBREE_RUST_UPDATE_BINDINGS` set to
+    /// update them
+    #[test]
+    fn pregenerated_bindings_up_to_date() {
+        let tokens = |bindings: &'static str| bindings.split_whitespace().collect::<Vec<_>>();
+        let generated = include_str!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+        #[cfg(not(feature = "embree4"))]
+        let pregenerated = include_str!("bindings/embree3.rs");
+        #[cfg(feature = "embree4")]
+        let pregenerated = include_str!("bindings/embree4.rs");
+        assert!(
+            tokens(generated) == tokens(pregenerated),
+            "pregenerated bindings in src/bindings are out of date"
+        );
+    }
+}
@@ -1 +1,5 @@
-#include "deps/embree3/include/embree3/rtcore.h"
+#ifdef EMBREE_RUST_EMBREE4
+#include <embree4/rtcore.h>
+#else
+#include <embree3/rtcore.h>
+#endif